@@ -13,6 +13,7 @@ impl Dispatcher {
             Msg::Copy(msg) => handler::copy::handle(model, ui, msg),
             Msg::Render(msg) => handler::render::handle(model, ui, msg),
             Msg::Skeleton(msg) => handler::skeleton::handle(model, ui, msg),
+            Msg::Symbols(msg) => handler::symbols::handle(model, ui, msg),
             Msg::Options(msg) => handler::options::handle(model, ui, msg),
             Msg::Filter(msg) => handler::filter::handle(model, ui, msg),
             Msg::App(msg) => handler::app::handle(model, ui, msg),
@@ -1,13 +1,16 @@
 use std::sync::Arc;
 
-use crate::app::message::{Cmd, Copy};
+use crate::app::message::{Cmd, Copy, Delivery};
 use crate::app::state::{Model, UiState};
 
+use super::deliver;
+
 pub fn handle(model: &mut Model, ui: &mut UiState, msg: Copy) -> Cmd {
     match msg {
         Copy::Requested => handle_copy_requested(model, ui),
+        Copy::MixedRequested => handle_mixed_requested(model, ui),
         Copy::Started => handle_copy_started(ui),
-        Copy::Completed(output) => handle_copy_completed(model, ui, output),
+        Copy::Completed(delivery) => handle_copy_completed(model, ui, delivery),
         Copy::Failed(error) => handle_copy_failed(ui, error),
     }
 }
@@ -42,16 +45,57 @@ fn handle_copy_requested(model: &mut Model, ui: &mut UiState) -> Cmd {
     }
 }
 
+fn handle_mixed_requested(model: &mut Model, ui: &mut UiState) -> Cmd {
+    if ui.copy_in_progress {
+        return Cmd::None;
+    }
+
+    for node in &mut model.tree.nodes {
+        node.expand_all_checked(&model.options);
+    }
+
+    model.refresh_git_status();
+
+    let paths = model.tree.gather_checked_paths_with_git(&model.search, Some(&model.git));
+
+    if paths.is_empty() {
+        return Cmd::None;
+    }
+
+    let roots = model
+        .tree
+        .nodes
+        .iter()
+        .map(|node| node.path.display().to_string())
+        .collect();
+
+    ui.copy_in_progress = true;
+    model.tree.output.clear();
+
+    let query = model.search.parsed();
+
+    Cmd::GatherMixed {
+        paths,
+        roots,
+        options: Arc::clone(&model.options),
+        git: model.git.clone(),
+        query,
+    }
+}
+
 fn handle_copy_started(ui: &mut UiState) -> Cmd {
     ui.copy_in_progress = true;
     Cmd::None
 }
 
-fn handle_copy_completed(model: &mut Model, ui: &mut UiState, message: String) -> Cmd {
+fn handle_copy_completed(model: &mut Model, ui: &mut UiState, delivery: Delivery) -> Cmd {
     model.tree.output = String::new();
     ui.copy_in_progress = false;
 
-    ui.toast.success(message);
+    match deliver(delivery) {
+        Ok(message) => ui.toast.success(message),
+        Err(e) => ui.toast.error(format!("Copy failed: {}", e)),
+    }
 
     Cmd::None
 }
@@ -6,9 +6,16 @@ pub mod render;
 pub mod search;
 pub mod session;
 pub mod skeleton;
+pub mod symbols;
 pub mod tree;
 
+use std::fs;
+
+use rfd::FileDialog;
+
+use crate::app::message::Delivery;
 use crate::app::state::Model;
+use crate::model::error::{SwarmError, SwarmResult};
 use crate::model::node::FileNode;
 use crate::model::options::Options;
 use crate::services::tree::traversal::Traversable;
@@ -19,6 +26,25 @@ pub fn sync_to_active_session(model: &mut Model) {
     model.sessions.sync_from_tree_and_search(model.tree.clone(), model.search.clone());
 }
 
+// Called from the UI thread, so the save dialog is never opened by a worker
+pub fn deliver(delivery: Delivery) -> SwarmResult<String> {
+    let (html, summary) = match delivery {
+        Delivery::Copied(summary) => return Ok(format!("{} copied", summary)),
+        Delivery::Export { html, summary } => (html, summary),
+    };
+
+    let path = FileDialog::new()
+        .set_title("Save HTML Export")
+        .set_file_name("context.html")
+        .add_filter("HTML", &["html", "htm"])
+        .save_file()
+        .ok_or_else(|| SwarmError::Other("Export cancelled".into()))?;
+
+    fs::write(&path, html)?;
+
+    Ok(format!("{} saved to {}", summary, path.display()))
+}
+
 pub fn load_node_children(nodes: &mut [FileNode], path: &[u32], options: &Options) {
     if path.is_empty() {
         return;
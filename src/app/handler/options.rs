@@ -13,7 +13,29 @@ pub fn handle(model: &mut Model, ui: &mut UiState, msg: Options_) -> Cmd {
         Options_::UseIconChanged(value) => handle_option_use_icon_changed(model, value),
         Options_::DeleteSessionsChanged(value) => handle_option_delete_sessions_changed(model, value),
         Options_::SingleInstanceChanged(value) => handle_option_single_instance_changed(model, value),
+        Options_::DependencyDepthChanged(value) => handle_option_dependency_depth_changed(model, value),
+        Options_::EmbedTreeChanged(embed) => handle_option_embed_tree_changed(model, embed),
+        Options_::LineNumbersChanged(value) => handle_option_line_numbers_changed(model, value),
+        Options_::MinifyBlankLinesChanged(value) => handle_option_minify_blank_lines_changed(model, value),
+        Options_::MinifyCommentsChanged(value) => handle_option_minify_comments_changed(model, value),
+        Options_::MinifyIndentChanged(style) => handle_option_minify_indent_changed(model, style),
+        Options_::MinifyTrailingWhitespaceChanged(value) => handle_option_minify_trailing_whitespace_changed(model, value),
         Options_::OutputFormatChanged(format) => handle_option_output_format_changed(model, format),
+        Options_::OutputTemplateChanged(name) => handle_option_output_template_changed(model, name),
+        Options_::PromptAdded => handle_option_prompt_added(model, ui),
+        Options_::PromptEdited(index, prompt) => handle_option_prompt_edited(model, index, prompt),
+        Options_::PromptEditorSelected(index) => handle_option_prompt_editor_selected(ui, index),
+        Options_::PromptRemoved(index) => handle_option_prompt_removed(model, ui, index),
+        Options_::PromptSelected(name) => handle_option_prompt_selected(model, name),
+        Options_::SkeletonDocCommentsChanged(value) => handle_option_skeleton_doc_comments_changed(model, value),
+        Options_::SkeletonDocSummaryChanged(value) => handle_option_skeleton_doc_summary_changed(model, value),
+        Options_::SkeletonExpandLinesChanged(value) => handle_option_skeleton_expand_lines_changed(model, value),
+        Options_::SkeletonLevelChanged(level) => handle_option_skeleton_level_changed(model, level),
+        Options_::SkeletonPublicOnlyChanged(value) => handle_option_skeleton_public_only_changed(model, value),
+        Options_::TruncateExcerptChanged(value) => handle_option_truncate_excerpt_changed(model, value),
+        Options_::TruncateLimitChanged(value) => handle_option_truncate_limit_changed(model, value),
+        Options_::TruncateModeChanged(mode) => handle_option_truncate_mode_changed(model, mode),
+        Options_::TruncateUnitChanged(unit) => handle_option_truncate_unit_changed(model, unit),
         Options_::UiScaleReset => handle_option_ui_scale_reset(model),
     }
 }
@@ -97,6 +119,76 @@ fn handle_option_single_instance_changed(model: &mut Model, value: bool) -> Cmd
     Cmd::None
 }
 
+fn handle_option_dependency_depth_changed(model: &mut Model, value: usize) -> Cmd {
+    let mut new_options = (*model.options).clone();
+    new_options.dependency_depth = value.max(1);
+
+    let _ = new_options.save();
+    model.update_options(new_options);
+
+    Cmd::None
+}
+
+fn handle_option_embed_tree_changed(model: &mut Model, embed: crate::model::output::TreeEmbed) -> Cmd {
+    let mut new_options = (*model.options).clone();
+    new_options.embed_tree = embed;
+
+    let _ = new_options.save();
+    model.update_options(new_options);
+
+    Cmd::None
+}
+
+fn handle_option_line_numbers_changed(model: &mut Model, value: bool) -> Cmd {
+    let mut new_options = (*model.options).clone();
+    new_options.line_numbers = value;
+
+    let _ = new_options.save();
+    model.update_options(new_options);
+
+    Cmd::None
+}
+
+fn handle_option_minify_blank_lines_changed(model: &mut Model, value: bool) -> Cmd {
+    let mut new_options = (*model.options).clone();
+    new_options.minify_blank_lines = value;
+
+    let _ = new_options.save();
+    model.update_options(new_options);
+
+    Cmd::None
+}
+
+fn handle_option_minify_comments_changed(model: &mut Model, value: bool) -> Cmd {
+    let mut new_options = (*model.options).clone();
+    new_options.minify_comments = value;
+
+    let _ = new_options.save();
+    model.update_options(new_options);
+
+    Cmd::None
+}
+
+fn handle_option_minify_indent_changed(model: &mut Model, style: crate::model::minify::IndentStyle) -> Cmd {
+    let mut new_options = (*model.options).clone();
+    new_options.minify_indent = style;
+
+    let _ = new_options.save();
+    model.update_options(new_options);
+
+    Cmd::None
+}
+
+fn handle_option_minify_trailing_whitespace_changed(model: &mut Model, value: bool) -> Cmd {
+    let mut new_options = (*model.options).clone();
+    new_options.minify_trailing_whitespace = value;
+
+    let _ = new_options.save();
+    model.update_options(new_options);
+
+    Cmd::None
+}
+
 fn handle_option_output_format_changed(model: &mut Model, format: crate::model::output::OutputFormat) -> Cmd {
     let mut new_options = (*model.options).clone();
     new_options.output_format = format;
@@ -107,6 +199,150 @@ fn handle_option_output_format_changed(model: &mut Model, format: crate::model::
     Cmd::None
 }
 
+fn handle_option_output_template_changed(model: &mut Model, name: String) -> Cmd {
+    let mut new_options = (*model.options).clone();
+    new_options.output_template = Some(name);
+
+    let _ = new_options.save();
+    model.update_options(new_options);
+
+    Cmd::None
+}
+
+fn handle_option_prompt_added(model: &mut Model, ui: &mut UiState) -> Cmd {
+    let mut new_options = (*model.options).clone();
+    ui.selected_prompt = Some(new_options.add_prompt());
+    model.update_options(new_options);
+
+    Cmd::None
+}
+
+fn handle_option_prompt_edited(model: &mut Model, index: usize, prompt: crate::model::prompt::Prompt) -> Cmd {
+    let mut new_options = (*model.options).clone();
+
+    if new_options.update_prompt(index, prompt) {
+        model.update_options(new_options);
+    }
+
+    Cmd::None
+}
+
+fn handle_option_prompt_editor_selected(ui: &mut UiState, index: usize) -> Cmd {
+    ui.selected_prompt = Some(index);
+    Cmd::None
+}
+
+fn handle_option_prompt_removed(model: &mut Model, ui: &mut UiState, index: usize) -> Cmd {
+    let mut new_options = (*model.options).clone();
+
+    if new_options.remove_prompt(index) {
+        ui.selected_prompt = index.checked_sub(1).or((!new_options.prompts.is_empty()).then_some(0));
+        model.update_options(new_options);
+    }
+
+    Cmd::None
+}
+
+fn handle_option_prompt_selected(model: &mut Model, name: Option<String>) -> Cmd {
+    let mut new_options = (*model.options).clone();
+    new_options.prompt = name;
+
+    let _ = new_options.save();
+    model.update_options(new_options);
+
+    Cmd::None
+}
+
+fn handle_option_skeleton_doc_comments_changed(model: &mut Model, value: bool) -> Cmd {
+    let mut new_options = (*model.options).clone();
+    new_options.skeleton_doc_comments = value;
+
+    let _ = new_options.save();
+    model.update_options(new_options);
+
+    Cmd::None
+}
+
+fn handle_option_skeleton_doc_summary_changed(model: &mut Model, value: bool) -> Cmd {
+    let mut new_options = (*model.options).clone();
+    new_options.skeleton_doc_summary = value;
+
+    let _ = new_options.save();
+    model.update_options(new_options);
+
+    Cmd::None
+}
+
+fn handle_option_skeleton_expand_lines_changed(model: &mut Model, value: usize) -> Cmd {
+    let mut new_options = (*model.options).clone();
+    new_options.skeleton_expand_lines = value.max(1);
+
+    let _ = new_options.save();
+    model.update_options(new_options);
+
+    Cmd::None
+}
+
+fn handle_option_skeleton_level_changed(model: &mut Model, level: crate::model::skeleton::SkeletonLevel) -> Cmd {
+    let mut new_options = (*model.options).clone();
+    new_options.skeleton_level = level;
+
+    let _ = new_options.save();
+    model.update_options(new_options);
+
+    Cmd::None
+}
+
+fn handle_option_skeleton_public_only_changed(model: &mut Model, value: bool) -> Cmd {
+    let mut new_options = (*model.options).clone();
+    new_options.skeleton_public_only = value;
+
+    let _ = new_options.save();
+    model.update_options(new_options);
+
+    Cmd::None
+}
+
+fn handle_option_truncate_excerpt_changed(model: &mut Model, value: usize) -> Cmd {
+    let mut new_options = (*model.options).clone();
+    new_options.truncate_excerpt = value;
+
+    let _ = new_options.save();
+    model.update_options(new_options);
+
+    Cmd::None
+}
+
+fn handle_option_truncate_limit_changed(model: &mut Model, value: Option<usize>) -> Cmd {
+    let mut new_options = (*model.options).clone();
+    new_options.truncate_limit = value;
+
+    let _ = new_options.save();
+    model.update_options(new_options);
+
+    Cmd::None
+}
+
+fn handle_option_truncate_mode_changed(model: &mut Model, mode: crate::model::truncate::TruncateMode) -> Cmd {
+    let mut new_options = (*model.options).clone();
+    new_options.truncate_mode = mode;
+
+    let _ = new_options.save();
+    model.update_options(new_options);
+
+    Cmd::None
+}
+
+fn handle_option_truncate_unit_changed(model: &mut Model, unit: crate::model::truncate::TruncateUnit) -> Cmd {
+    let mut new_options = (*model.options).clone();
+    new_options.truncate_unit = unit;
+
+    let _ = new_options.save();
+    model.update_options(new_options);
+
+    Cmd::None
+}
+
 fn handle_option_ui_scale_reset(model: &mut Model) -> Cmd {
     let mut new_options = (*model.options).clone();
     new_options.ui_scale = None;
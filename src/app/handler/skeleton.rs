@@ -1,15 +1,18 @@
 use std::sync::Arc;
 
-use crate::app::message::{Cmd, Skeleton};
+use crate::app::message::{Cmd, Delivery, Skeleton};
 use crate::app::state::{Model, UiState};
 use crate::app::state::ui::GenerateMode;
 
+use super::deliver;
+
 pub fn handle(model: &mut Model, ui: &mut UiState, msg: Skeleton) -> Cmd {
     match msg {
         Skeleton::ModeChanged(mode) => handle_mode_changed(ui, mode),
         Skeleton::Requested => handle_skeleton_requested(model, ui),
         Skeleton::Started => handle_skeleton_started(ui),
-        Skeleton::Generated(output) => handle_skeleton_generated(model, ui, output),
+        Skeleton::Progress { processed, total } => handle_skeleton_progress(ui, processed, total),
+        Skeleton::Generated(delivery) => handle_skeleton_generated(model, ui, delivery),
         Skeleton::Failed(error) => handle_skeleton_failed(ui, error),
     }
 }
@@ -24,6 +27,10 @@ fn handle_skeleton_requested(model: &mut Model, ui: &mut UiState) -> Cmd {
         return Cmd::None;
     }
 
+    for node in &mut model.tree.nodes {
+        node.expand_all_checked(&model.options);
+    }
+
     model.refresh_git_status();
 
     let paths = model.tree.gather_checked_paths_with_git(&model.search, Some(&model.git));
@@ -34,28 +41,64 @@ fn handle_skeleton_requested(model: &mut Model, ui: &mut UiState) -> Cmd {
 
     ui.skeleton_gen_in_progress = true;
 
+    let query = model.search.parsed();
+
+    let options = if query.format_override.is_some() || query.skeleton_level_override.is_some() {
+        let mut options = (*model.options).clone();
+
+        if let Some(format) = query.format_override {
+            options.output_format = format;
+        }
+
+        if let Some(level) = query.skeleton_level_override {
+            options.skeleton_level = level;
+        }
+
+        Arc::new(options)
+    } else {
+        Arc::clone(&model.options)
+    };
+
     Cmd::GenerateSkeleton {
         paths,
-        options: Arc::clone(&model.options),
+        options,
+        git: model.git.clone(),
+        query,
     }
 }
 
 fn handle_skeleton_started(ui: &mut UiState) -> Cmd {
     ui.skeleton_gen_in_progress = true;
+    ui.skeleton_progress = None;
     Cmd::None
 }
 
-fn handle_skeleton_generated(model: &mut Model, ui: &mut UiState, output: String) -> Cmd {
-    model.tree.output = output;
-    ui.skeleton_gen_in_progress = false;
+fn handle_skeleton_progress(ui: &mut UiState, processed: usize, total: usize) -> Cmd {
+    if ui.skeleton_gen_in_progress {
+        ui.skeleton_progress = Some((processed, total));
+    }
+
+    Cmd::None
+}
 
-    ui.toast.success("Skeleton copied to clipboard");
+fn handle_skeleton_generated(model: &mut Model, ui: &mut UiState, delivery: Delivery) -> Cmd {
+    ui.skeleton_gen_in_progress = false;
+    ui.skeleton_progress = None;
+
+    match deliver(delivery) {
+        Ok(message) => {
+            model.tree.output = message.clone();
+            ui.toast.success(message);
+        }
+        Err(e) => ui.toast.error(format!("Skeleton failed: {}", e)),
+    }
 
     Cmd::None
 }
 
 fn handle_skeleton_failed(ui: &mut UiState, error: String) -> Cmd {
     ui.skeleton_gen_in_progress = false;
+    ui.skeleton_progress = None;
     eprintln!("Skeleton generation failed: {}", error);
 
     ui.toast.error(format!("Skeleton failed: {}", error));
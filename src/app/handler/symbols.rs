@@ -0,0 +1,93 @@
+use std::sync::Arc;
+
+use crate::app::message::{Cmd, Delivery, Symbols};
+use crate::app::state::{Model, UiState};
+
+use super::deliver;
+
+pub fn handle(model: &mut Model, ui: &mut UiState, msg: Symbols) -> Cmd {
+    match msg {
+        Symbols::Requested => handle_symbols_requested(model, ui),
+        Symbols::Started => handle_symbols_started(ui),
+        Symbols::Progress { processed, total } => handle_symbols_progress(ui, processed, total),
+        Symbols::Generated(delivery) => handle_symbols_generated(model, ui, delivery),
+        Symbols::Failed(error) => handle_symbols_failed(ui, error),
+    }
+}
+
+fn handle_symbols_requested(model: &mut Model, ui: &mut UiState) -> Cmd {
+    if ui.symbols_gen_in_progress {
+        return Cmd::None;
+    }
+
+    for node in &mut model.tree.nodes {
+        node.expand_all_checked(&model.options);
+    }
+
+    model.refresh_git_status();
+
+    let paths = model.tree.gather_checked_paths_with_git(&model.search, Some(&model.git));
+
+    if paths.is_empty() {
+        return Cmd::None;
+    }
+
+    ui.symbols_gen_in_progress = true;
+
+    let query = model.search.parsed();
+
+    let options = match query.format_override {
+        Some(format) => {
+            let mut options = (*model.options).clone();
+            options.output_format = format;
+            Arc::new(options)
+        }
+        None => Arc::clone(&model.options),
+    };
+
+    Cmd::GenerateSymbols {
+        paths,
+        options,
+        git: model.git.clone(),
+        query,
+    }
+}
+
+fn handle_symbols_started(ui: &mut UiState) -> Cmd {
+    ui.symbols_gen_in_progress = true;
+    ui.symbols_progress = None;
+    Cmd::None
+}
+
+fn handle_symbols_progress(ui: &mut UiState, processed: usize, total: usize) -> Cmd {
+    if ui.symbols_gen_in_progress {
+        ui.symbols_progress = Some((processed, total));
+    }
+
+    Cmd::None
+}
+
+fn handle_symbols_generated(model: &mut Model, ui: &mut UiState, delivery: Delivery) -> Cmd {
+    ui.symbols_gen_in_progress = false;
+    ui.symbols_progress = None;
+
+    match deliver(delivery) {
+        Ok(message) => {
+            model.tree.output = message.clone();
+            ui.toast.success(message);
+        }
+        Err(e) => ui.toast.error(format!("Symbol map failed: {}", e)),
+    }
+
+    Cmd::None
+}
+
+fn handle_symbols_failed(ui: &mut UiState, error: String) -> Cmd {
+    ui.symbols_gen_in_progress = false;
+    ui.symbols_progress = None;
+    eprintln!("Symbol map generation failed: {}", error);
+
+    ui.toast.error(format!("Symbol map failed: {}", error));
+
+    Cmd::None
+}
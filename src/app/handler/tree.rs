@@ -1,7 +1,8 @@
+use std::path::PathBuf;
 use std::sync::Arc;
 
 use crate::app::message::{Cmd, CmdBuilder, Tree};
-use crate::app::state::{LoadStatus, Model, UiState};
+use crate::app::state::{LoadStatus, Model, SearchModel, UiState};
 use crate::model::node::FileNode;
 
 use super::{load_node_children, sync_to_active_session, toggle_node};
@@ -19,6 +20,11 @@ pub fn handle(model: &mut Model, ui: &mut UiState, msg: Tree) -> Cmd {
         Tree::PropagateFailed(error) => handle_propagate_failed(error),
         Tree::BackgroundLoadProgress { loaded, total } => { handle_background_load_progress(model, loaded, total) }
         Tree::BackgroundLoadCompleted(nodes) => { handle_background_load_completed(model, nodes) }
+        Tree::DependenciesRequested => handle_dependencies_requested(model),
+        Tree::DependenciesResolved(files) => handle_dependencies_resolved(model, ui, files),
+        Tree::DependentsRequested(target) => handle_dependents_requested(model, target),
+        Tree::DependentsResolved(files) => handle_dependents_resolved(model, ui, files),
+        Tree::DependentsFailed(error) => handle_dependents_failed(ui, error),
     }
 }
 
@@ -203,3 +209,89 @@ fn handle_background_load_completed(model: &mut Model, nodes: Vec<FileNode>) ->
 
     Cmd::None
 }
+
+fn handle_dependencies_requested(model: &mut Model) -> Cmd {
+    for node in &mut model.tree.nodes {
+        node.expand_all_checked(&model.options);
+    }
+
+    let files: Vec<PathBuf> = model.tree
+        .gather_checked_paths(&SearchModel::default())
+        .into_iter()
+        .map(PathBuf::from)
+        .collect();
+
+    if files.is_empty() {
+        return Cmd::None;
+    }
+
+    let roots = model.tree.nodes.iter().map(|node| node.path.clone()).collect();
+
+    Cmd::ExpandDependencies {
+        files,
+        roots,
+        depth: model.options.dependency_depth,
+    }
+}
+
+fn handle_dependencies_resolved(model: &mut Model, ui: &mut UiState, files: Vec<PathBuf>) -> Cmd {
+    let checked = check_files(model, &files);
+
+    if checked == 0 {
+        ui.toast.success("No new dependencies found");
+        return Cmd::None;
+    }
+
+    model.tree.update_file_count();
+    sync_to_active_session(model);
+
+    ui.toast.success(format!("Checked {} dependencies", checked));
+
+    Cmd::None
+}
+
+fn handle_dependents_requested(model: &mut Model, target: PathBuf) -> Cmd {
+    let roots = model.tree.nodes.iter().map(|node| node.path.clone()).collect();
+
+    Cmd::FindDependents {
+        target,
+        roots,
+        options: Arc::clone(&model.options),
+    }
+}
+
+fn handle_dependents_resolved(model: &mut Model, ui: &mut UiState, files: Vec<PathBuf>) -> Cmd {
+    let checked = check_files(model, &files);
+
+    if checked == 0 {
+        ui.toast.success("No new dependents found");
+        return Cmd::None;
+    }
+
+    model.tree.update_file_count();
+    sync_to_active_session(model);
+
+    ui.toast.success(format!("Checked {} dependents", checked));
+
+    Cmd::None
+}
+
+fn handle_dependents_failed(ui: &mut UiState, error: String) -> Cmd {
+    eprintln!("Dependent lookup failed: {}", error);
+
+    ui.toast.error(format!("Dependent lookup failed: {}", error));
+
+    Cmd::None
+}
+
+fn check_files(model: &mut Model, files: &[PathBuf]) -> usize {
+    let mut checked = 0;
+
+    for file in files {
+        if model.tree.nodes.iter_mut().any(|node| node.check_path(file, &model.options)) {
+            checked += 1;
+        }
+    }
+
+    checked
+}
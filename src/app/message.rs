@@ -5,9 +5,13 @@ use std::sync::Arc;
 use crate::app::state::search::ParsedQuery;
 use crate::app::state::ui::GenerateMode;
 use crate::app::state::OptionsTab;
+use crate::model::minify::IndentStyle;
 use crate::model::node::FileNode;
 use crate::model::options::Options;
-use crate::model::output::OutputFormat;
+use crate::model::output::{OutputFormat, TreeEmbed};
+use crate::model::prompt::Prompt;
+use crate::model::skeleton::SkeletonLevel;
+use crate::model::truncate::{TruncateMode, TruncateUnit};
 use crate::services::filesystem::git::GitService;
 use crate::ui::themes::Theme;
 
@@ -19,6 +23,7 @@ pub enum Msg {
     Copy(Copy),
     Render(Render),
     Skeleton(Skeleton),
+    Symbols(Symbols),
     Options(Options_),
     Filter(Filter),
     App(App),
@@ -48,6 +53,11 @@ pub enum Tree {
     PropagateFailed(String),
     BackgroundLoadProgress { loaded: usize, total: usize },
     BackgroundLoadCompleted(Vec<FileNode>),
+    DependenciesRequested,
+    DependenciesResolved(Vec<PathBuf>),
+    DependentsRequested(PathBuf),
+    DependentsResolved(Vec<PathBuf>),
+    DependentsFailed(String),
 }
 
 #[derive(Debug, Clone)]
@@ -65,8 +75,9 @@ pub enum Search {
 #[derive(Debug, Clone)]
 pub enum Copy {
     Requested,
+    MixedRequested,
     Started,
-    Completed(String),
+    Completed(Delivery),
     Failed(String),
 }
 
@@ -83,10 +94,26 @@ pub enum Skeleton {
     ModeChanged(GenerateMode),
     Requested,
     Started,
-    Generated(String),
+    Progress { processed: usize, total: usize },
+    Generated(Delivery),
+    Failed(String),
+}
+
+#[derive(Debug, Clone)]
+pub enum Symbols {
+    Requested,
+    Started,
+    Progress { processed: usize, total: usize },
+    Generated(Delivery),
     Failed(String),
 }
 
+#[derive(Debug, Clone)]
+pub enum Delivery {
+    Copied(String),
+    Export { html: String, summary: String },
+}
+
 #[derive(Debug, Clone)]
 pub enum Options_ {
     Opened,
@@ -98,7 +125,29 @@ pub enum Options_ {
     UseIconChanged(bool),
     DeleteSessionsChanged(bool),
     SingleInstanceChanged(bool),
+    DependencyDepthChanged(usize),
+    EmbedTreeChanged(TreeEmbed),
+    LineNumbersChanged(bool),
+    MinifyBlankLinesChanged(bool),
+    MinifyCommentsChanged(bool),
+    MinifyIndentChanged(IndentStyle),
+    MinifyTrailingWhitespaceChanged(bool),
     OutputFormatChanged(OutputFormat),
+    OutputTemplateChanged(String),
+    PromptAdded,
+    PromptEdited(usize, Prompt),
+    PromptEditorSelected(usize),
+    PromptRemoved(usize),
+    PromptSelected(Option<String>),
+    SkeletonDocCommentsChanged(bool),
+    SkeletonDocSummaryChanged(bool),
+    SkeletonExpandLinesChanged(usize),
+    SkeletonLevelChanged(SkeletonLevel),
+    SkeletonPublicOnlyChanged(bool),
+    TruncateExcerptChanged(usize),
+    TruncateLimitChanged(Option<usize>),
+    TruncateModeChanged(TruncateMode),
+    TruncateUnitChanged(TruncateUnit),
 }
 
 #[derive(Debug, Clone)]
@@ -129,8 +178,12 @@ pub enum Cmd {
     LoadSession { path: PathBuf, options: Arc<Options> },
     RefreshTree { nodes: Vec<FileNode>, options: Arc<Options> },
     GatherFiles { paths: Vec<String>, options: Arc<Options>, git: GitService, query: ParsedQuery },
+    GatherMixed { paths: Vec<String>, roots: Vec<String>, options: Arc<Options>, git: GitService, query: ParsedQuery },
     RenderTree { nodes: Vec<FileNode>, options: Arc<Options> },
-    GenerateSkeleton { paths: Vec<String>, options: Arc<Options> },
+    GenerateSkeleton { paths: Vec<String>, options: Arc<Options>, git: GitService, query: ParsedQuery },
+    GenerateSymbols { paths: Vec<String>, options: Arc<Options>, git: GitService, query: ParsedQuery },
+    ExpandDependencies { files: Vec<PathBuf>, roots: Vec<PathBuf>, depth: usize },
+    FindDependents { target: PathBuf, roots: Vec<PathBuf>, options: Arc<Options> },
     SaveSessions,
     DeleteSessionData(String),
     PropagateCheckedWithLoad {
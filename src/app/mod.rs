@@ -156,6 +156,7 @@ impl eframe::App for SwarmApp {
         ) || self.ui.copy_in_progress
           || self.ui.tree_gen_in_progress
           || self.ui.skeleton_gen_in_progress
+          || self.ui.symbols_gen_in_progress
           || self.model.background_loader.is_running()
           || self.ui.search_debounce.is_some()
           || self.ui.filter_status == FilterStatus::Filtering
@@ -5,14 +5,18 @@ use std::thread;
 
 use copypasta::{ClipboardContext, ClipboardProvider};
 
-use crate::app::message::{Cmd, Copy, Msg, Render, Search, Skeleton};
+use crate::app::message::{Cmd, Copy, Delivery, Msg, Render, Search, Skeleton, Symbols};
 use crate::app::state::{SessionData, SessionsModel};
 use crate::constants::APP_NAME;
 use crate::model::node::FileNode;
 use crate::model::options::Options;
+use crate::model::output::{OutputContext, OutputFormat};
+use crate::model::prompt::apply_prompt;
+use crate::services::dependency::DependencyResolver;
 use crate::services::filesystem::gather::GatherService;
 use crate::services::filesystem::git::GitService;
 use crate::services::skeleton::SkeletonGenerator;
+use crate::services::symbol::{SymbolIndex, SymbolMap};
 use crate::app::state::search::ParsedQuery;
 use crate::services::tree::generator::TreeGenerator;
 use crate::services::tree::traversal::Traversable;
@@ -30,6 +34,7 @@ pub struct Runtime {
     session_loader: SessionLoader,
     skeleton_gen_tx: Option<Sender<()>>,
     skeleton_generator: SkeletonGenerator,
+    symbols_gen_tx: Option<Sender<()>>,
     tree_gen_tx: Option<Sender<()>>,
     tree_loader: TreeLoader,
 }
@@ -44,6 +49,7 @@ impl Runtime {
             session_loader: SessionLoader::new(),
             skeleton_gen_tx: None,
             skeleton_generator: SkeletonGenerator::new(),
+            symbols_gen_tx: None,
             tree_gen_tx: None,
             tree_loader: TreeLoader::new(),
         }
@@ -93,12 +99,28 @@ impl Runtime {
                 self.execute_gather(paths, options, git, query);
             }
 
+            Cmd::GatherMixed { paths, roots, options, git, query } => {
+                self.execute_gather_mixed(paths, roots, options, git, query);
+            }
+
             Cmd::RenderTree { nodes, options } => {
                 self.execute_tree_render(nodes, options);
             }
 
-            Cmd::GenerateSkeleton { paths, options } => {
-                self.execute_skeleton_generate(paths, options);
+            Cmd::GenerateSkeleton { paths, options, git, query } => {
+                self.execute_skeleton_generate(paths, options, git, query);
+            }
+
+            Cmd::GenerateSymbols { paths, options, git, query } => {
+                self.execute_symbols_generate(paths, options, git, query);
+            }
+
+            Cmd::ExpandDependencies { files, roots, depth } => {
+                self.execute_expand_dependencies(files, roots, depth);
+            }
+
+            Cmd::FindDependents { target, roots, options } => {
+                self.execute_find_dependents(target, roots, options);
             }
 
             Cmd::SaveSessions => {
@@ -250,14 +272,53 @@ impl Runtime {
                 return;
             }
 
-            match gather.gather_with_context(&paths, &options, Some(&git), Some(&query)) {
-                Ok((output, stats)) => {
-                    if let Ok(mut clipboard) = ClipboardContext::new() {
-                        let _ = clipboard.set_contents(output.clone());
-                    }
+            let format = query.format_override.unwrap_or(options.output_format);
+
+            let result = gather.gather_with_context(&paths, &options, Some(&git), Some(&query))
+                .map(|(output, stats)| deliver(output, stats.summary(), format));
+
+            match result {
+                Ok(delivery) => {
+                    let _ = sender.send(Msg::Copy(Copy::Completed(delivery)));
+                }
+                Err(e) => {
+                    let _ = sender.send(Msg::Copy(Copy::Failed(e.to_string())));
+                }
+            }
+        });
+    }
+
+    fn execute_gather_mixed(
+        &mut self,
+        paths: Vec<String>,
+        roots: Vec<String>,
+        options: Arc<Options>,
+        git: GitService,
+        query: ParsedQuery,
+    ) {
+        let gather = self.gather_service.clone();
+        let sender = self.msg_sender.clone();
+
+        sender.send(Msg::Copy(Copy::Started)).ok();
+
+        let (tx, rx) = mpsc::channel();
+        self.gather_tx = Some(tx);
+
+        thread::spawn(move || {
+            thread::sleep(std::time::Duration::from_millis(500));
 
-                    let message = format!("{} lines / {} tokens copied", stats.line_count, stats.token_count);
-                    let _ = sender.send(Msg::Copy(Copy::Completed(message)));
+            if rx.try_recv().is_ok() {
+                return;
+            }
+
+            let format = query.format_override.unwrap_or(options.output_format);
+
+            let result = gather.gather_mixed(&paths, &roots, &options, Some(&git), Some(&query))
+                .map(|(output, stats)| deliver(output, stats.summary(), format));
+
+            match result {
+                Ok(delivery) => {
+                    let _ = sender.send(Msg::Copy(Copy::Completed(delivery)));
                 }
                 Err(e) => {
                     let _ = sender.send(Msg::Copy(Copy::Failed(e.to_string())));
@@ -292,7 +353,7 @@ impl Runtime {
         });
     }
 
-    fn execute_skeleton_generate(&mut self, paths: Vec<String>, options: Arc<Options>) {
+    fn execute_skeleton_generate(&mut self, paths: Vec<String>, options: Arc<Options>, git: GitService, query: ParsedQuery) {
         let generator = self.skeleton_generator.clone();
         let sender = self.msg_sender.clone();
 
@@ -308,18 +369,23 @@ impl Runtime {
                 return;
             }
 
-            match generator.generate(&paths, &options) {
-                Ok((output, stats)) => {
-                    if let Ok(mut clipboard) = ClipboardContext::new() {
-                        let _ = clipboard.set_contents(output.clone());
-                    }
+            let mut progress = |processed: usize, total: usize| {
+                let _ = sender.send(Msg::Skeleton(Skeleton::Progress { processed, total }));
+            };
 
-                    let message = format!(
-                        "{} files / {} lines / {} tokens skeleton copied",
+            let result = generator.generate_with_progress(&paths, &options, Some(&git), Some(&query), &mut progress)
+                .map(|(output, stats)| {
+                    let summary = format!(
+                        "{} files / {} lines / {} tokens skeleton",
                         stats.file_count, stats.line_count, stats.token_count,
                     );
 
-                    let _ = sender.send(Msg::Skeleton(Skeleton::Generated(message)));
+                    deliver(output, summary, options.output_format)
+                });
+
+            match result {
+                Ok(delivery) => {
+                    let _ = sender.send(Msg::Skeleton(Skeleton::Generated(delivery)));
                 }
                 Err(e) => {
                     let _ = sender.send(Msg::Skeleton(Skeleton::Failed(e.to_string())));
@@ -328,6 +394,75 @@ impl Runtime {
         });
     }
 
+    fn execute_symbols_generate(&mut self, paths: Vec<String>, options: Arc<Options>, git: GitService, query: ParsedQuery) {
+        let sender = self.msg_sender.clone();
+
+        sender.send(Msg::Symbols(Symbols::Started)).ok();
+
+        let (tx, rx) = mpsc::channel();
+        self.symbols_gen_tx = Some(tx);
+
+        thread::spawn(move || {
+            thread::sleep(std::time::Duration::from_millis(500));
+
+            if rx.try_recv().is_ok() {
+                return;
+            }
+
+            let mut progress = |processed: usize, total: usize| {
+                let _ = sender.send(Msg::Symbols(Symbols::Progress { processed, total }));
+            };
+
+            let result = SymbolIndex::build_with_progress(&paths, &options, Some(&git), Some(&query), &mut progress)
+                .and_then(|index| {
+                    let context = OutputContext::new(options.output_format, &options, Some(&git))?;
+                    let output = SymbolMap::render(&index, options.output_format, &context)?;
+                    let output = apply_prompt(output, &options, &paths, Some(&git), index.file_count())?;
+                    Ok((index, output))
+                });
+
+            let result = result.map(|(index, output)| {
+                let summary = format!("{} files / {} symbols map", index.file_count(), index.len());
+                deliver(output, summary, options.output_format)
+            });
+
+            match result {
+                Ok(delivery) => {
+                    let _ = sender.send(Msg::Symbols(Symbols::Generated(delivery)));
+                }
+                Err(e) => {
+                    let _ = sender.send(Msg::Symbols(Symbols::Failed(e.to_string())));
+                }
+            }
+        });
+    }
+
+    fn execute_expand_dependencies(&self, files: Vec<PathBuf>, roots: Vec<PathBuf>, depth: usize) {
+        let sender = self.msg_sender.clone();
+
+        thread::spawn(move || {
+            let resolver = DependencyResolver::new(&roots);
+            let dependencies = resolver.expand(&files, depth);
+
+            let _ = sender.send(Msg::Tree(crate::app::message::Tree::DependenciesResolved(dependencies)));
+        });
+    }
+
+    fn execute_find_dependents(&self, target: PathBuf, roots: Vec<PathBuf>, options: Arc<Options>) {
+        let sender = self.msg_sender.clone();
+
+        thread::spawn(move || {
+            let resolver = DependencyResolver::new(&roots);
+
+            let msg = match resolver.dependents(&target, &options) {
+                Ok(dependents) => crate::app::message::Tree::DependentsResolved(dependents),
+                Err(e) => crate::app::message::Tree::DependentsFailed(e.to_string()),
+            };
+
+            let _ = sender.send(Msg::Tree(msg));
+        });
+    }
+
     fn execute_propagate_with_load(
         &mut self,
         mut nodes: Vec<FileNode>,
@@ -377,3 +512,16 @@ impl Runtime {
         }
     }
 }
+
+// HTML pages are returned to the UI to be saved rather than copied to the clipboard
+fn deliver(output: String, summary: String, format: OutputFormat) -> Delivery {
+    if format == OutputFormat::Html {
+        return Delivery::Export { html: output, summary };
+    }
+
+    if let Ok(mut clipboard) = ClipboardContext::new() {
+        let _ = clipboard.set_contents(output);
+    }
+
+    Delivery::Copied(summary)
+}
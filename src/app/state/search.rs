@@ -5,6 +5,7 @@ use std::time::{Duration, SystemTime};
 use serde::{Deserialize, Serialize};
 
 use crate::model::output::OutputFormat;
+use crate::model::skeleton::SkeletonLevel;
 use crate::services::filesystem::git::GitStatus;
 
 #[derive(Clone, Default, Deserialize, Serialize)]
@@ -60,6 +61,7 @@ pub struct ParsedQuery {
     pub git_filters: Vec<GitFilter>,
     pub lines_max: Option<u64>,
     pub lines_min: Option<u64>,
+    pub max_lines: Option<usize>,
     pub name_excludes: Vec<String>,
     pub names: Vec<String>,
     pub path_excludes: Vec<String>,
@@ -67,12 +69,15 @@ pub struct ParsedQuery {
     pub recent_duration: Option<Duration>,
     pub size_max: Option<u64>,
     pub size_min: Option<u64>,
+    pub skeleton_level_override: Option<SkeletonLevel>,
+    pub symbols: Vec<String>,
     pub type_filter: Option<TypeFilter>,
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum Command {
     Diff,
+    LineNumbers,
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -378,6 +383,9 @@ impl ParsedQuery {
                         result.depth_max = value.trim().parse().ok();
                     }
                 }
+                "maxlines" | "ml" => {
+                    result.max_lines = value.trim().parse().ok();
+                }
                 "recent" | "r" => {
                     result.recent_duration = Self::parse_duration(value);
                 }
@@ -386,6 +394,15 @@ impl ParsedQuery {
                         result.content_patterns.push(value.to_string());
                     }
                 }
+                "symbol" | "sym" => {
+                    for part in value.split(',') {
+                        let symbol = part.trim();
+
+                        if !symbol.is_empty() {
+                            result.symbols.push(symbol.to_string());
+                        }
+                    }
+                }
                 _ => {
                     result.contains.push(token.to_lowercase());
                 }
@@ -401,6 +418,9 @@ impl ParsedQuery {
             "diff" | "d" => {
                 result.commands.push(Command::Diff);
             }
+            "line-numbers" | "ln" => {
+                result.commands.push(Command::LineNumbers);
+            }
             "plain" | "plain-text" | "text" => {
                 result.format_override = Some(OutputFormat::PlainText);
             }
@@ -410,9 +430,27 @@ impl ParsedQuery {
             "json" => {
                 result.format_override = Some(OutputFormat::Json);
             }
+            "jsonl" | "json-lines" => {
+                result.format_override = Some(OutputFormat::JsonLines);
+            }
             "xml" => {
                 result.format_override = Some(OutputFormat::Xml);
             }
+            "documents" | "docs" => {
+                result.format_override = Some(OutputFormat::Documents);
+            }
+            "html" => {
+                result.format_override = Some(OutputFormat::Html);
+            }
+            "signatures" | "sig" => {
+                result.skeleton_level_override = Some(SkeletonLevel::Signatures);
+            }
+            "outline" => {
+                result.skeleton_level_override = Some(SkeletonLevel::Outline);
+            }
+            "expanded" | "exp" => {
+                result.skeleton_level_override = Some(SkeletonLevel::Expanded);
+            }
             _ => {}
         }
     }
@@ -559,6 +597,7 @@ impl ParsedQuery {
             && self.git_filters.is_empty()
             && self.lines_max.is_none()
             && self.lines_min.is_none()
+            && self.max_lines.is_none()
             && self.name_excludes.is_empty()
             && self.names.is_empty()
             && self.path_excludes.is_empty()
@@ -566,6 +605,8 @@ impl ParsedQuery {
             && self.recent_duration.is_none()
             && self.size_max.is_none()
             && self.size_min.is_none()
+            && self.skeleton_level_override.is_none()
+            && self.symbols.is_empty()
             && self.type_filter.is_none()
     }
 
@@ -594,7 +635,11 @@ impl ParsedQuery {
     }
 
     pub fn needs_content(&self) -> bool {
-        !self.content_patterns.is_empty()
+        !self.content_patterns.is_empty() || self.has_symbol_filter()
+    }
+
+    pub fn has_symbol_filter(&self) -> bool {
+        !self.symbols.is_empty()
     }
 
     pub fn needs_metadata(&self) -> bool {
@@ -759,6 +804,21 @@ impl ParsedQuery {
                         return false;
                     }
                 }
+
+                if !self.symbols.is_empty() {
+                    if let Some(content) = &meta.content {
+                        let mentions_symbol = self.symbols.iter().any(|symbol| {
+                            let name = symbol.rsplit([':', '.']).next().unwrap_or(symbol);
+                            content.contains(name)
+                        });
+
+                        if !mentions_symbol {
+                            return false;
+                        }
+                    } else {
+                        return false;
+                    }
+                }
             } else if self.needs_metadata() {
                 return false;
             }
@@ -9,6 +9,7 @@ pub enum OptionsTab {
     #[default]
     General,
     Includes,
+    Prompts,
 }
 
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
@@ -24,6 +25,8 @@ pub enum GenerateMode {
     #[default]
     Tree,
     Skeleton,
+    Symbols,
+    Mixed,
 }
 
 #[derive(Clone, Default)]
@@ -62,10 +65,14 @@ pub struct UiState {
     pub options_tab: OptionsTab,
     pub search_debounce: Option<Instant>,
     pub search_pending: Option<String>,
+    pub selected_prompt: Option<usize>,
     pub should_focus: bool,
     pub show_about: bool,
     pub show_options: bool,
     pub skeleton_gen_in_progress: bool,
+    pub skeleton_progress: Option<(usize, usize)>,
+    pub symbols_gen_in_progress: bool,
+    pub symbols_progress: Option<(usize, usize)>,
     pub theme: Theme,
     pub toast: ToastSystem,
     pub tree_gen_in_progress: bool,
@@ -85,10 +92,14 @@ impl UiState {
             options_tab: OptionsTab::default(),
             search_debounce: None,
             search_pending: None,
+            selected_prompt: None,
             should_focus: false,
             show_about: false,
             show_options: false,
             skeleton_gen_in_progress: false,
+            skeleton_progress: None,
+            symbols_gen_in_progress: false,
+            symbols_progress: None,
             theme,
             toast: ToastSystem::new(),
             tree_gen_in_progress: false,
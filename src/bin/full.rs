@@ -11,14 +11,34 @@ use swarm::{SwarmApp, APP_NAME};
 use swarm::cli;
 
 const CLI_FLAGS: &[&str] = &[
+    "--collapse-blank-lines",
     "--diff", "-d",
+    "--doc-comments",
+    "--doc-summary",
+    "--embed-tree",
+    "--excerpt-lines",
     "--format", "-f",
     "--help", "-h",
+    "--indent",
+    "--line-numbers", "--ln",
+    "--max-bytes",
+    "--max-lines",
+    "--max-tokens",
+    "--minify",
+    "--mixed",
     "--output", "-o",
+    "--prompt",
+    "--public-only",
     "--search", "-s",
     "--skeleton", "-k",
+    "--skeleton-level",
     "--stdout",
+    "--strip-comments",
+    "--symbols",
+    "--template",
     "--tree", "-t",
+    "--trim-whitespace",
+    "--truncate-to-skeleton",
     "--version", "-V",
 ];
 
@@ -5,11 +5,16 @@ use std::process;
 use clap::{Parser, ValueEnum};
 use copypasta::{ClipboardContext, ClipboardProvider};
 
+use crate::model::minify::IndentStyle;
 use crate::model::options::Options;
-use crate::model::output::OutputFormat;
+use crate::model::output::{OutputContext, OutputFormat, TreeEmbed};
+use crate::model::prompt::apply_prompt;
+use crate::model::skeleton::SkeletonLevel;
+use crate::model::truncate::{TruncateMode, TruncateUnit};
 use crate::services::filesystem::gather::GatherService;
 use crate::services::filesystem::git::GitService;
 use crate::services::skeleton::SkeletonGenerator;
+use crate::services::symbol::{SymbolIndex, SymbolMap};
 use crate::services::tree::generator::TreeGenerator;
 use crate::services::tree::loader;
 use crate::model::node::FileNode;
@@ -19,7 +24,11 @@ pub enum Format {
     Plain,
     Markdown,
     Json,
+    JsonLines,
     Xml,
+    Documents,
+    Html,
+    Template,
 }
 
 impl From<Format> for OutputFormat {
@@ -28,7 +37,60 @@ impl From<Format> for OutputFormat {
             Format::Plain => OutputFormat::PlainText,
             Format::Markdown => OutputFormat::Markdown,
             Format::Json => OutputFormat::Json,
+            Format::JsonLines => OutputFormat::JsonLines,
             Format::Xml => OutputFormat::Xml,
+            Format::Documents => OutputFormat::Documents,
+            Format::Html => OutputFormat::Html,
+            Format::Template => OutputFormat::Template,
+        }
+    }
+}
+
+#[derive(Clone, Debug, ValueEnum)]
+pub enum Level {
+    Signatures,
+    Outline,
+    Expanded,
+}
+
+impl From<Level> for SkeletonLevel {
+    fn from(level: Level) -> Self {
+        match level {
+            Level::Signatures => SkeletonLevel::Signatures,
+            Level::Outline => SkeletonLevel::Outline,
+            Level::Expanded => SkeletonLevel::Expanded,
+        }
+    }
+}
+
+#[derive(Clone, Debug, ValueEnum)]
+pub enum Embed {
+    Selection,
+    Project,
+}
+
+impl From<Embed> for TreeEmbed {
+    fn from(embed: Embed) -> Self {
+        match embed {
+            Embed::Selection => TreeEmbed::Selection,
+            Embed::Project => TreeEmbed::Project,
+        }
+    }
+}
+
+#[derive(Clone, Debug, ValueEnum)]
+pub enum Indent {
+    Keep,
+    Tabs,
+    Minimal,
+}
+
+impl From<Indent> for IndentStyle {
+    fn from(indent: Indent) -> Self {
+        match indent {
+            Indent::Keep => IndentStyle::Keep,
+            Indent::Tabs => IndentStyle::Tabs,
+            Indent::Minimal => IndentStyle::Minimal,
         }
     }
 }
@@ -53,9 +115,75 @@ pub struct Cli {
     #[arg(short, long, value_enum, help = "Output format")]
     pub format: Option<Format>,
 
+    #[arg(long, conflicts_with = "format", help = "Render output through a template file")]
+    pub template: Option<PathBuf>,
+
+    #[arg(
+        long,
+        value_enum,
+        num_args = 0..=1,
+        default_missing_value = "selection",
+        help = "Prefix output with a directory tree of the selected files or the whole project"
+    )]
+    pub embed_tree: Option<Embed>,
+
+    #[arg(long, help = "Strip comments, collapse blank lines and trim trailing whitespace")]
+    pub minify: bool,
+
+    #[arg(long, help = "Strip comments from file contents")]
+    pub strip_comments: bool,
+
+    #[arg(long, help = "Collapse runs of blank lines into one")]
+    pub collapse_blank_lines: bool,
+
+    #[arg(long, help = "Trim trailing whitespace from every line")]
+    pub trim_whitespace: bool,
+
+    #[arg(long, value_enum, help = "Rewrite indentation as tabs or a single space per level")]
+    pub indent: Option<Indent>,
+
+    #[arg(long, value_name = "N", conflicts_with_all = ["max_tokens", "max_bytes"], help = "Truncate files longer than N lines")]
+    pub max_lines: Option<usize>,
+
+    #[arg(long, value_name = "N", conflicts_with = "max_bytes", help = "Truncate files larger than N estimated tokens")]
+    pub max_tokens: Option<usize>,
+
+    #[arg(long, value_name = "N", help = "Truncate files larger than N bytes")]
+    pub max_bytes: Option<usize>,
+
+    #[arg(long, value_name = "N", help = "Lines kept at the start and end of truncated files")]
+    pub excerpt_lines: Option<usize>,
+
+    #[arg(long, help = "Replace truncated files with their skeleton")]
+    pub truncate_to_skeleton: bool,
+
+    #[arg(long, visible_alias = "ln", help = "Prefix each line with its source line number")]
+    pub line_numbers: bool,
+
+    #[arg(long, value_name = "NAME", help = "Wrap output in a saved prompt preset")]
+    pub prompt: Option<String>,
+
     #[arg(short = 'k', long, help = "Output file skeletons instead of file contents")]
     pub skeleton: bool,
 
+    #[arg(long, value_enum, help = "Skeleton detail level")]
+    pub skeleton_level: Option<Level>,
+
+    #[arg(long, help = "Only include public items in skeletons")]
+    pub public_only: bool,
+
+    #[arg(long, help = "Keep doc comments and docstrings in skeletons")]
+    pub doc_comments: bool,
+
+    #[arg(long, help = "Keep only the first paragraph of skeleton doc comments")]
+    pub doc_summary: bool,
+
+    #[arg(long, conflicts_with_all = ["skeleton", "tree"], help = "Output a map of every type and function per file")]
+    pub symbols: bool,
+
+    #[arg(long, requires = "search", help = "Output files matching the search in full and skeletons for the rest")]
+    pub mixed: bool,
+
     #[arg(short, long, help = "Write output to a file instead of stdout")]
     pub output: Option<PathBuf>,
 
@@ -69,7 +197,7 @@ pub struct Cli {
 pub fn run(cli: Cli) {
     let options = Options::load().unwrap_or_default();
 
-    if cli.skeleton {
+    if cli.skeleton || cli.symbols {
         let path = &cli.path;
 
         if !path.exists() {
@@ -77,7 +205,12 @@ pub fn run(cli: Cli) {
             process::exit(1);
         }
 
-        let output = run_skeleton(path, &options, &cli);
+        let output = if cli.symbols {
+            run_symbols(path, &options, &cli)
+        } else {
+            run_skeleton(path, &options, &cli)
+        };
+
         output_result(&output, &cli);
         return;
     }
@@ -138,20 +271,32 @@ fn output_result(output: &str, cli: &Cli) {
 }
 
 fn run_skeleton(path: &Path, options: &Options, cli: &Cli) -> String {
+    let mut git = GitService::new();
+    git.refresh(&normalize_path(path));
+
+    let mut query = match cli.search {
+        Some(ref search) => crate::app::state::search::ParsedQuery::parse(search),
+        None => crate::app::state::search::ParsedQuery::default(),
+    };
+
     let mut override_options = options.clone();
 
-    if let Some(ref search) = cli.search {
-        let query = crate::app::state::search::ParsedQuery::parse(search);
+    if let Some(level) = query.skeleton_level_override {
+        override_options.skeleton_level = level;
+    }
 
-        if let Some(format) = query.format_override {
-            override_options.output_format = format;
-        }
+    // An explicit --format takes precedence over a format in the query
+    if let Some(format) = query.format_override.take() {
+        override_options.output_format = format;
     }
 
+    apply_format_flags(&mut override_options, cli);
+    apply_skeleton_flags(&mut override_options, cli);
+
     let generator = SkeletonGenerator::new();
     let paths = vec![path.display().to_string()];
 
-    match generator.generate(&paths, &override_options) {
+    match generator.generate_with_context(&paths, &override_options, Some(&git), Some(&query)) {
         Ok((output, stats)) => {
             eprintln!("{} files / {} lines / {} tokens", stats.file_count, stats.line_count, stats.token_count);
             output
@@ -163,6 +308,126 @@ fn run_skeleton(path: &Path, options: &Options, cli: &Cli) -> String {
     }
 }
 
+fn run_symbols(path: &Path, options: &Options, cli: &Cli) -> String {
+    let mut git = GitService::new();
+    git.refresh(&normalize_path(path));
+
+    let query = match cli.search {
+        Some(ref search) => crate::app::state::search::ParsedQuery::parse(search),
+        None => crate::app::state::search::ParsedQuery::default(),
+    };
+
+    let mut override_options = options.clone();
+
+    if let Some(format) = query.format_override {
+        override_options.output_format = format;
+    }
+
+    apply_format_flags(&mut override_options, cli);
+
+    let output_format = override_options.output_format;
+    let paths = vec![path.display().to_string()];
+
+    let index = match SymbolIndex::build(&paths, &override_options, Some(&git), Some(&query)) {
+        Ok(index) => index,
+        Err(error) => {
+            eprintln!("Error: {}", error);
+            process::exit(1);
+        }
+    };
+
+    let result = OutputContext::new(output_format, &override_options, Some(&git))
+        .and_then(|context| SymbolMap::render(&index, output_format, &context))
+        .and_then(|output| apply_prompt(output, &override_options, &paths, Some(&git), index.file_count()));
+
+    match result {
+        Ok(output) => {
+            eprintln!("{} files / {} symbols", index.file_count(), index.len());
+            output
+        }
+        Err(error) => {
+            eprintln!("Error: {}", error);
+            process::exit(1);
+        }
+    }
+}
+
+fn apply_format_flags(options: &mut Options, cli: &Cli) {
+    if let Some(ref format) = cli.format {
+        options.output_format = format.clone().into();
+    }
+
+    if let Some(ref template) = cli.template {
+        options.output_format = OutputFormat::Template;
+        options.output_template = Some(template.display().to_string());
+    }
+
+    if let Some(ref embed) = cli.embed_tree {
+        options.embed_tree = embed.clone().into();
+    }
+
+    if cli.line_numbers {
+        options.line_numbers = true;
+    }
+
+    if cli.minify || cli.strip_comments {
+        options.minify_comments = true;
+    }
+
+    if cli.minify || cli.collapse_blank_lines {
+        options.minify_blank_lines = true;
+    }
+
+    if cli.minify || cli.trim_whitespace {
+        options.minify_trailing_whitespace = true;
+    }
+
+    if let Some(ref indent) = cli.indent {
+        options.minify_indent = indent.clone().into();
+    }
+
+    let limits = [
+        (cli.max_lines, TruncateUnit::Lines),
+        (cli.max_tokens, TruncateUnit::Tokens),
+        (cli.max_bytes, TruncateUnit::Bytes),
+    ];
+
+    if let Some((limit, unit)) = limits.into_iter().find_map(|(limit, unit)| Some((limit?, unit))) {
+        options.truncate_limit = Some(limit);
+        options.truncate_unit = unit;
+    }
+
+    if let Some(excerpt) = cli.excerpt_lines {
+        options.truncate_excerpt = excerpt;
+    }
+
+    if cli.truncate_to_skeleton {
+        options.truncate_mode = TruncateMode::Skeleton;
+    }
+
+    if let Some(ref prompt) = cli.prompt {
+        options.prompt = Some(prompt.clone());
+    }
+}
+
+fn apply_skeleton_flags(options: &mut Options, cli: &Cli) {
+    if let Some(ref level) = cli.skeleton_level {
+        options.skeleton_level = level.clone().into();
+    }
+
+    if cli.public_only {
+        options.skeleton_public_only = true;
+    }
+
+    if cli.doc_comments || cli.doc_summary {
+        options.skeleton_doc_comments = true;
+    }
+
+    if cli.doc_summary {
+        options.skeleton_doc_summary = true;
+    }
+}
+
 fn run_tree(path: &Path, options: &Options) -> String {
     let mut root = FileNode::new(path.to_path_buf());
 
@@ -181,7 +446,7 @@ fn run_gather(path: &Path, options: &Options, cli: &Cli) -> String {
 
     let query_string = build_query_string(cli);
 
-    let query = if query_string.is_empty() {
+    let mut query = if query_string.is_empty() {
         crate::app::state::search::ParsedQuery::default()
     } else {
         crate::app::state::search::ParsedQuery::parse(&query_string)
@@ -189,16 +454,37 @@ fn run_gather(path: &Path, options: &Options, cli: &Cli) -> String {
 
     let mut override_options = options.clone();
 
-    if let Some(ref format) = cli.format {
-        override_options.output_format = format.clone().into();
+    // An explicit --format takes precedence over a format in the query
+    if let Some(format) = query.format_override.take() {
+        override_options.output_format = format;
+    }
+
+    apply_format_flags(&mut override_options, cli);
+
+    if cli.mixed {
+        if let Some(level) = query.skeleton_level_override {
+            override_options.skeleton_level = level;
+        }
+
+        apply_skeleton_flags(&mut override_options, cli);
     }
 
     let gather = GatherService::new();
     let paths = vec![path.display().to_string()];
 
-    match gather.gather_with_context(&paths, &override_options, Some(&git), Some(&query)) {
+    let result = if cli.mixed {
+        gather
+            .matching_files(&paths, &override_options, Some(&git), &query)
+            .and_then(|full_paths| {
+                gather.gather_mixed(&full_paths, &paths, &override_options, Some(&git), Some(&query))
+            })
+    } else {
+        gather.gather_with_context(&paths, &override_options, Some(&git), Some(&query))
+    };
+
+    match result {
         Ok((output, stats)) => {
-            eprintln!("{} lines / {} tokens", stats.line_count, stats.token_count);
+            eprintln!("{}", stats.summary());
             output
         }
         Err(error) => {
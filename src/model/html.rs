@@ -0,0 +1,132 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use crate::services::highlight::escape_html;
+
+const STYLE: &str = r#"
+:root { color-scheme: light dark; --bg: #ffffff; --fg: #1f2328; --muted: #656d76; --panel: #f6f8fa; --border: #d0d7de; --accent: #0969da; }
+@media (prefers-color-scheme: dark) { :root { --bg: #0d1117; --fg: #e6edf3; --muted: #8d96a0; --panel: #161b22; --border: #30363d; --accent: #4493f8; } }
+* { box-sizing: border-box; }
+body { margin: 0; display: flex; font: 14px/1.5 system-ui, sans-serif; background: var(--bg); color: var(--fg); }
+nav { position: sticky; top: 0; height: 100vh; width: 300px; flex-shrink: 0; overflow: auto; padding: 16px; background: var(--panel); border-right: 1px solid var(--border); }
+nav ul { list-style: none; margin: 0; padding-left: 14px; }
+nav > ul { padding-left: 0; }
+nav summary { cursor: pointer; color: var(--muted); }
+nav a { color: var(--fg); text-decoration: none; word-break: break-all; }
+nav a:hover { color: var(--accent); }
+main { flex: 1; min-width: 0; padding: 24px; }
+h1 { margin: 0 0 4px; font-size: 20px; }
+.stats, .meta { color: var(--muted); }
+section { margin-top: 24px; border: 1px solid var(--border); border-radius: 6px; overflow: hidden; }
+section header { display: flex; flex-wrap: wrap; gap: 12px; align-items: baseline; padding: 8px 12px; background: var(--panel); border-bottom: 1px solid var(--border); }
+section h2 { margin: 0; font-size: 14px; font-family: ui-monospace, monospace; word-break: break-all; }
+pre { margin: 0; padding: 12px; overflow: auto; font: 13px/1.45 ui-monospace, SFMono-Regular, Menlo, Consolas, monospace; }
+.numbered { display: flex; }
+.numbered pre:last-child { flex: 1; min-width: 0; }
+.gutter { flex-shrink: 0; overflow: hidden; text-align: right; color: var(--muted); border-right: 1px solid var(--border); user-select: none; }
+.comment { color: #6e7781; font-style: italic; }
+.string { color: #0a3069; }
+.number, .constant { color: #0550ae; }
+.keyword { color: #cf222e; }
+.type { color: #953800; }
+.function { color: #8250df; }
+.property { color: #116329; }
+@media (prefers-color-scheme: dark) {
+  .comment { color: #8b949e; } .string { color: #a5d6ff; } .number, .constant { color: #79c0ff; }
+  .keyword { color: #ff7b72; } .type { color: #ffa657; } .function { color: #d2a8ff; } .property { color: #7ee787; }
+}
+"#;
+
+pub struct HtmlFile {
+    pub meta: Vec<String>,
+    pub path: String,
+    pub body: String,
+    pub numbers: Option<Vec<Option<usize>>>,
+}
+
+#[derive(Default)]
+struct Directory<'a> {
+    directories: BTreeMap<&'a str, Directory<'a>>,
+    files: Vec<(&'a str, usize)>,
+}
+
+impl<'a> Directory<'a> {
+    fn insert(&mut self, path: &'a str, index: usize) {
+        match path.split_once(['/', '\\']) {
+            Some((name, rest)) if !name.is_empty() => {
+                self.directories.entry(name).or_default().insert(rest, index);
+            }
+            Some((_, rest)) => self.insert(rest, index),
+            None => self.files.push((path, index)),
+        }
+    }
+
+    fn render(&self, output: &mut String) {
+        output.push_str("<ul>\n");
+
+        for (name, directory) in &self.directories {
+            let _ = writeln!(output, "<li><details open><summary>{}</summary>", escape_html(name));
+            directory.render(output);
+            output.push_str("</details></li>\n");
+        }
+
+        for (name, index) in &self.files {
+            let _ = writeln!(output, "<li><a href=\"#file-{}\">{}</a></li>", index, escape_html(name));
+        }
+
+        output.push_str("</ul>\n");
+    }
+}
+
+pub fn render_page(title: &str, summary: &str, tree: Option<&str>, files: &[HtmlFile]) -> String {
+    let mut sidebar = Directory::default();
+
+    for (index, file) in files.iter().enumerate() {
+        sidebar.insert(&file.path, index + 1);
+    }
+
+    let mut output = String::from("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+
+    let _ = writeln!(output, "<title>{}</title>", escape_html(title));
+    let _ = writeln!(output, "<style>{}</style>\n</head>\n<body>\n<nav>", STYLE);
+
+    sidebar.render(&mut output);
+
+    let _ = writeln!(output, "</nav>\n<main>\n<h1>{}</h1>", escape_html(title));
+    let _ = writeln!(output, "<div class=\"stats\">{}</div>", escape_html(summary));
+
+    if let Some(tree) = tree {
+        let _ = writeln!(output, "<section><header><h2>Tree</h2></header><pre>{}</pre></section>", escape_html(tree));
+    }
+
+    for (index, file) in files.iter().enumerate() {
+        let _ = writeln!(output, "<section id=\"file-{}\">", index + 1);
+        let _ = writeln!(
+            output,
+            "<header><h2>{}</h2><span class=\"meta\">{}</span></header>",
+            escape_html(&file.path),
+            escape_html(&file.meta.join(" · ")),
+        );
+
+        match file.numbers {
+            Some(ref numbers) => {
+                let gutter: Vec<String> = numbers.iter()
+                    .map(|number| number.map(|number| number.to_string()).unwrap_or_default())
+                    .collect();
+
+                let _ = writeln!(
+                    output,
+                    "<div class=\"numbered\"><pre class=\"gutter\">{}</pre><pre><code>{}</code></pre></div>\n</section>",
+                    gutter.join("\n"),
+                    file.body,
+                );
+            }
+            None => {
+                let _ = writeln!(output, "<pre><code>{}</code></pre>\n</section>", file.body);
+            }
+        }
+    }
+
+    output.push_str("</main>\n</body>\n</html>\n");
+    output
+}
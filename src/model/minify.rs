@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub enum IndentStyle {
+    #[default]
+    Keep,
+    Tabs,
+    Minimal,
+}
+
+impl IndentStyle {
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Keep => "Keep",
+            Self::Tabs => "Tabs",
+            Self::Minimal => "Minimal",
+        }
+    }
+
+    pub fn all() -> &'static [IndentStyle] {
+        &[
+            Self::Keep,
+            Self::Tabs,
+            Self::Minimal,
+        ]
+    }
+}
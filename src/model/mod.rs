@@ -1,11 +1,18 @@
 pub mod error;
+pub mod html;
+pub mod minify;
 pub mod node;
 pub mod options;
 pub mod output;
 pub mod path;
+pub mod prompt;
+pub mod skeleton;
+pub mod template;
+pub mod truncate;
 
 pub use error::{SwarmError, SwarmResult};
 pub use node::{FileNode, NodeKind};
 pub use options::Options;
 pub use output::OutputFormat;
 pub use path::PathExtensions;
+pub use skeleton::SkeletonLevel;
@@ -1,4 +1,4 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use serde::{Deserialize, Serialize};
 
@@ -225,6 +225,24 @@ impl FileNode {
         }
     }
 
+    pub fn check_path(&mut self, target: &Path, options: &Options) -> bool {
+        if self.path == target {
+            let newly_checked = !self.checked;
+            self.checked = true;
+            return newly_checked;
+        }
+
+        if !self.is_directory() || !target.starts_with(&self.path) {
+            return false;
+        }
+
+        if !self.loaded {
+            let _ = self.load_children(options);
+        }
+
+        self.children.iter_mut().any(|child| child.check_path(target, options))
+    }
+
     pub fn filter_selected(&self, query: &str) -> Option<FileNode> {
         let parsed = ParsedQuery::parse(query);
         self.filter_selected_with_git(&parsed, None)
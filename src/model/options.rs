@@ -5,7 +5,11 @@ use serde::{Deserialize, Serialize};
 
 use crate::constants::APP_NAME;
 use crate::model::error::{SwarmError, SwarmResult};
-use crate::model::output::OutputFormat;
+use crate::model::minify::IndentStyle;
+use crate::model::output::{OutputFormat, TreeEmbed};
+use crate::model::prompt::Prompt;
+use crate::model::skeleton::SkeletonLevel;
+use crate::model::truncate::{TruncateMode, TruncateUnit};
 use crate::ui::themes::Theme;
 
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
@@ -13,21 +17,78 @@ pub struct Options {
     #[serde(default)]
     pub delete_sessions_on_exit: bool,
 
+    #[serde(default = "default_dependency_depth")]
+    pub dependency_depth: usize,
+
+    #[serde(default)]
+    pub embed_tree: TreeEmbed,
+
     #[serde(default = "default_exclude_patterns")]
     pub exclude: Vec<String>,
 
     #[serde(default)]
     pub include: Vec<String>,
 
+    #[serde(default)]
+    pub line_numbers: bool,
+
+    #[serde(default)]
+    pub minify_blank_lines: bool,
+
+    #[serde(default)]
+    pub minify_comments: bool,
+
+    #[serde(default)]
+    pub minify_indent: IndentStyle,
+
+    #[serde(default)]
+    pub minify_trailing_whitespace: bool,
+
     #[serde(default)]
     pub output_format: OutputFormat,
 
+    #[serde(default)]
+    pub output_template: Option<String>,
+
+    #[serde(default)]
+    pub prompt: Option<String>,
+
+    #[serde(default)]
+    pub prompts: Vec<Prompt>,
+
     #[serde(default = "default_single_instance")]
     pub single_instance: bool,
 
+    #[serde(default)]
+    pub skeleton_doc_comments: bool,
+
+    #[serde(default)]
+    pub skeleton_doc_summary: bool,
+
+    #[serde(default = "default_skeleton_expand_lines")]
+    pub skeleton_expand_lines: usize,
+
+    #[serde(default)]
+    pub skeleton_level: SkeletonLevel,
+
+    #[serde(default)]
+    pub skeleton_public_only: bool,
+
     #[serde(default)]
     pub theme: Theme,
 
+    #[serde(default = "default_truncate_excerpt")]
+    pub truncate_excerpt: usize,
+
+    #[serde(default)]
+    pub truncate_limit: Option<usize>,
+
+    #[serde(default)]
+    pub truncate_mode: TruncateMode,
+
+    #[serde(default)]
+    pub truncate_unit: TruncateUnit,
+
     #[serde(default)]
     pub ui_scale: Option<f32>,
 
@@ -35,6 +96,10 @@ pub struct Options {
     pub use_icon: bool,
 }
 
+fn default_dependency_depth() -> usize {
+    1
+}
+
 fn default_exclude_patterns() -> Vec<String> {
     vec![
         ".git".to_string(),
@@ -136,6 +201,14 @@ fn default_single_instance() -> bool {
     true
 }
 
+fn default_skeleton_expand_lines() -> usize {
+    10
+}
+
+fn default_truncate_excerpt() -> usize {
+    50
+}
+
 pub fn calculate_default_ui_scale() -> f32 {
     if let Some(scale) = detect_screen_scale() {
         return scale;
@@ -279,11 +352,30 @@ impl Default for Options {
     fn default() -> Self {
         Self {
             delete_sessions_on_exit: false,
+            dependency_depth: default_dependency_depth(),
+            embed_tree: TreeEmbed::default(),
             exclude: default_exclude_patterns(),
             include: Vec::new(),
+            line_numbers: false,
+            minify_blank_lines: false,
+            minify_comments: false,
+            minify_indent: IndentStyle::default(),
+            minify_trailing_whitespace: false,
             output_format: OutputFormat::default(),
+            output_template: None,
+            prompt: None,
+            prompts: Vec::new(),
             single_instance: true,
+            skeleton_doc_comments: false,
+            skeleton_doc_summary: false,
+            skeleton_expand_lines: default_skeleton_expand_lines(),
+            skeleton_level: SkeletonLevel::default(),
+            skeleton_public_only: false,
             theme: Theme::default(),
+            truncate_excerpt: default_truncate_excerpt(),
+            truncate_limit: None,
+            truncate_mode: TruncateMode::default(),
+            truncate_unit: TruncateUnit::default(),
             ui_scale: None,
             use_icon: false,
         }
@@ -355,6 +447,24 @@ impl Options {
         true
     }
 
+    pub fn add_prompt(&mut self) -> usize {
+        let mut name = String::from("New Prompt");
+        let mut suffix = 2;
+
+        while self.prompts.iter().any(|prompt| prompt.name == name) {
+            name = format!("New Prompt {}", suffix);
+            suffix += 1;
+        }
+
+        self.prompts.push(Prompt {
+            name,
+            ..Prompt::default()
+        });
+
+        let _ = self.save();
+        self.prompts.len() - 1
+    }
+
     pub fn clear_includes(&mut self) {
         self.include.clear();
         let _ = self.save();
@@ -362,11 +472,30 @@ impl Options {
 
     pub fn is_equal(&self, other: &Self) -> bool {
         self.delete_sessions_on_exit == other.delete_sessions_on_exit
+            && self.dependency_depth == other.dependency_depth
+            && self.embed_tree == other.embed_tree
             && self.exclude == other.exclude
             && self.include == other.include
+            && self.line_numbers == other.line_numbers
+            && self.minify_blank_lines == other.minify_blank_lines
+            && self.minify_comments == other.minify_comments
+            && self.minify_indent == other.minify_indent
+            && self.minify_trailing_whitespace == other.minify_trailing_whitespace
             && self.output_format == other.output_format
+            && self.output_template == other.output_template
+            && self.prompt == other.prompt
+            && self.prompts == other.prompts
             && self.single_instance == other.single_instance
+            && self.skeleton_doc_comments == other.skeleton_doc_comments
+            && self.skeleton_doc_summary == other.skeleton_doc_summary
+            && self.skeleton_expand_lines == other.skeleton_expand_lines
+            && self.skeleton_level == other.skeleton_level
+            && self.skeleton_public_only == other.skeleton_public_only
             && self.theme == other.theme
+            && self.truncate_excerpt == other.truncate_excerpt
+            && self.truncate_limit == other.truncate_limit
+            && self.truncate_mode == other.truncate_mode
+            && self.truncate_unit == other.truncate_unit
             && self.ui_scale == other.ui_scale
             && self.use_icon == other.use_icon
     }
@@ -392,11 +521,41 @@ impl Options {
         true
     }
 
+    pub fn remove_prompt(&mut self, index: usize) -> bool {
+        if index >= self.prompts.len() {
+            return false;
+        }
+
+        let prompt = self.prompts.remove(index);
+
+        if self.prompt.as_deref() == Some(prompt.name.as_str()) {
+            self.prompt = None;
+        }
+
+        let _ = self.save();
+        true
+    }
+
     pub fn reset_excludes_to_defaults(&mut self) {
         self.exclude = default_exclude_patterns();
         let _ = self.save();
     }
 
+    pub fn update_prompt(&mut self, index: usize, prompt: Prompt) -> bool {
+        let Some(existing) = self.prompts.get_mut(index) else {
+            return false;
+        };
+
+        if self.prompt.as_deref() == Some(existing.name.as_str()) {
+            self.prompt = Some(prompt.name.clone());
+        }
+
+        *existing = prompt;
+
+        let _ = self.save();
+        true
+    }
+
     fn config_path() -> SwarmResult<PathBuf> {
         dirs::data_local_dir()
             .map(|dir| dir.join(APP_NAME.to_lowercase()).join("options.toml"))
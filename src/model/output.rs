@@ -1,9 +1,19 @@
-use std::collections::HashMap;
+use std::borrow::Cow;
 use std::fmt::Write as FmtWrite;
+use std::path::{Path, PathBuf};
 
 use serde::{Deserialize, Serialize};
 
 use crate::model::error::{SwarmError, SwarmResult};
+use crate::model::html::{self, HtmlFile};
+use crate::model::options::Options;
+use crate::model::path::PathExtensions;
+use crate::model::template::{self, Template};
+use crate::services::filesystem::gather::estimate_tokens;
+use crate::services::filesystem::git::GitService;
+use crate::services::filesystem::numbering::LineNumbers;
+use crate::services::highlight::highlight;
+use crate::services::skeleton::Language;
 
 #[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
 pub enum OutputFormat {
@@ -11,7 +21,153 @@ pub enum OutputFormat {
     PlainText,
     Markdown,
     Json,
+    JsonLines,
     Xml,
+    Documents,
+    Html,
+    Template,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub enum TreeEmbed {
+    #[default]
+    Off,
+    Selection,
+    Project,
+}
+
+impl TreeEmbed {
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Off => "Off",
+            Self::Selection => "Selected files",
+            Self::Project => "Project with selection marked",
+        }
+    }
+
+    pub fn all() -> &'static [TreeEmbed] {
+        &[
+            Self::Off,
+            Self::Selection,
+            Self::Project,
+        ]
+    }
+}
+
+// A gathered file. The variant marks alternate renderings of the same path,
+// such as the original side of a diff, a skeleton or an extracted symbol.
+// Line numbers are kept next to the content rather than written into it.
+#[derive(Clone, Debug)]
+pub struct OutputFile {
+    pub path: String,
+    pub variant: Option<String>,
+    pub content: String,
+    pub numbers: Option<LineNumbers>,
+}
+
+impl OutputFile {
+    pub fn new(path: impl Into<String>, content: String) -> Self {
+        Self {
+            path: path.into(),
+            variant: None,
+            content,
+            numbers: None,
+        }
+    }
+
+    pub fn with_variant(mut self, variant: impl Into<String>) -> Self {
+        self.variant = Some(variant.into());
+        self
+    }
+
+    pub fn with_numbers(mut self, numbers: Option<LineNumbers>) -> Self {
+        self.numbers = numbers;
+        self
+    }
+
+    // The content as text formats print it, prefixed with any line numbers
+    pub fn text(&self) -> Cow<'_, str> {
+        match self.numbers {
+            Some(ref numbers) => Cow::Owned(numbers.apply(&self.content)),
+            None => Cow::Borrowed(&self.content),
+        }
+    }
+
+    pub fn label(&self) -> String {
+        self.label_with(&self.path)
+    }
+
+    // Labels the file under a different spelling of its path, e.g. a relative one
+    pub fn label_with(&self, path: &str) -> String {
+        match self.variant {
+            Some(ref variant) => format!("{} ({})", path, variant),
+            None => path.to_string(),
+        }
+    }
+}
+
+pub const JSON_SCHEMA_VERSION: u32 = 1;
+
+#[derive(Serialize)]
+struct JsonOutput<'a> {
+    schema_version: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tree: Option<&'a str>,
+    files: Vec<JsonFile<'a>>,
+}
+
+#[derive(Serialize)]
+struct JsonTree<'a> {
+    schema_version: u32,
+    tree: &'a str,
+}
+
+#[derive(Serialize)]
+struct JsonLine<'a> {
+    schema_version: u32,
+    #[serde(flatten)]
+    file: JsonFile<'a>,
+}
+
+#[derive(Serialize)]
+struct JsonFile<'a> {
+    path: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    variant: Option<&'a str>,
+    language: Option<&'static str>,
+    size: usize,
+    lines: usize,
+    tokens: usize,
+    git_status: Option<&'static str>,
+    content: Cow<'a, str>,
+}
+
+#[derive(Clone, Default)]
+pub struct OutputContext<'a> {
+    pub git: Option<&'a GitService>,
+    pub root: Option<PathBuf>,
+    pub template: Option<Template>,
+    pub tree: Option<String>,
+}
+
+impl<'a> OutputContext<'a> {
+    pub fn new(format: OutputFormat, options: &Options, git: Option<&'a GitService>) -> SwarmResult<Self> {
+        let template = if format == OutputFormat::Template {
+            let name = options.output_template.as_deref()
+                .ok_or_else(|| SwarmError::Config("No output template selected".into()))?;
+
+            Some(Template::load(name)?)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            git,
+            root: git.and_then(|git| git.repo_root().map(Path::to_path_buf)),
+            template,
+            tree: None,
+        })
+    }
 }
 
 impl OutputFormat {
@@ -20,7 +176,11 @@ impl OutputFormat {
             Self::PlainText => "Plain Text",
             Self::Markdown => "Markdown",
             Self::Json => "JSON",
+            Self::JsonLines => "JSON Lines",
             Self::Xml => "XML",
+            Self::Documents => "XML Documents",
+            Self::Html => "HTML",
+            Self::Template => "Template",
         }
     }
 
@@ -29,64 +189,149 @@ impl OutputFormat {
             Self::PlainText,
             Self::Markdown,
             Self::Json,
+            Self::JsonLines,
             Self::Xml,
+            Self::Documents,
+            Self::Html,
+            Self::Template,
         ]
     }
 
-    pub fn format(&self, files: &[(String, String)]) -> SwarmResult<String> {
+    pub fn format(&self, files: &[OutputFile]) -> SwarmResult<String> {
+        self.format_with(files, &OutputContext::default())
+    }
+
+    pub fn format_with(&self, files: &[OutputFile], context: &OutputContext) -> SwarmResult<String> {
         match self {
-            Self::PlainText => Self::format_plain_text(files),
-            Self::Markdown => Self::format_markdown(files),
-            Self::Json => Self::format_json(files),
-            Self::Xml => Self::format_xml(files),
+            Self::PlainText => Self::format_plain_text(files, context),
+            Self::Markdown => Self::format_markdown(files, context),
+            Self::Json => Self::format_json(files, context),
+            Self::JsonLines => Self::format_json_lines(files, context),
+            Self::Xml => Self::format_xml(files, context),
+            Self::Documents => Self::format_documents(files, context),
+            Self::Html => Self::format_html(files, context),
+            Self::Template => Self::format_template(files, context),
         }
     }
 
-    fn format_plain_text(files: &[(String, String)]) -> SwarmResult<String> {
+    fn format_plain_text(files: &[OutputFile], context: &OutputContext) -> SwarmResult<String> {
         let mut output = String::new();
 
-        for (path, content) in files {
-            writeln!(output, "[{}]", path)
+        if let Some(ref tree) = context.tree {
+            writeln!(output, "[tree]\n{}", tree)
                 .map_err(|e| SwarmError::Other(format!("Failed to write: {}", e)))?;
-            writeln!(output, "{}", content)
+        }
+
+        for file in files {
+            writeln!(output, "[{}]", file.label())
+                .map_err(|e| SwarmError::Other(format!("Failed to write: {}", e)))?;
+            writeln!(output, "{}", file.text())
                 .map_err(|e| SwarmError::Other(format!("Failed to write content: {}", e)))?;
         }
 
         Ok(output)
     }
 
-    fn format_markdown(files: &[(String, String)]) -> SwarmResult<String> {
+    fn format_markdown(files: &[OutputFile], context: &OutputContext) -> SwarmResult<String> {
         let mut output = String::new();
 
-        for (path, content) in files {
-            writeln!(output, "## {}\n", path)
+        if let Some(ref tree) = context.tree {
+            let fence = Self::fence(tree);
+
+            writeln!(output, "## Tree\n\n{}\n{}{}\n", fence, tree, fence)
                 .map_err(|e| SwarmError::Other(format!("Failed to write: {}", e)))?;
-            writeln!(output, "```")
+        }
+
+        for file in files {
+            let content = file.text();
+            let fence = Self::fence(&content);
+
+            writeln!(output, "## {}\n", file.label())
                 .map_err(|e| SwarmError::Other(format!("Failed to write: {}", e)))?;
-            writeln!(output, "{}", content)
+            writeln!(output, "{}{}", fence, Self::fence_language(&file.path))
+                .map_err(|e| SwarmError::Other(format!("Failed to write: {}", e)))?;
+            writeln!(output, "{}", content.strip_suffix('\n').unwrap_or(&content))
                 .map_err(|e| SwarmError::Other(format!("Failed to write content: {}", e)))?;
-            writeln!(output, "```\n")
+            writeln!(output, "{}\n", fence)
                 .map_err(|e| SwarmError::Other(format!("Failed to write: {}", e)))?;
         }
 
         Ok(output)
     }
 
-    fn format_json(files: &[(String, String)]) -> SwarmResult<String> {
-        let map: HashMap<&str, &str> = files.iter()
-            .map(|(path, content)| (path.as_str(), content.as_str()))
-            .collect();
+    fn format_json(files: &[OutputFile], context: &OutputContext) -> SwarmResult<String> {
+        let output = JsonOutput {
+            schema_version: JSON_SCHEMA_VERSION,
+            tree: context.tree.as_deref(),
+            files: Self::json_files(files, context),
+        };
 
-        serde_json::to_string_pretty(&map)
+        serde_json::to_string_pretty(&output)
             .map_err(|e| SwarmError::Other(format!("Failed to serialize JSON: {}", e)))
     }
 
-    fn format_xml(files: &[(String, String)]) -> SwarmResult<String> {
+    fn format_json_lines(files: &[OutputFile], context: &OutputContext) -> SwarmResult<String> {
+        let mut output = String::new();
+
+        if let Some(ref tree) = context.tree {
+            let line = JsonTree {
+                schema_version: JSON_SCHEMA_VERSION,
+                tree,
+            };
+
+            let line = serde_json::to_string(&line)
+                .map_err(|e| SwarmError::Other(format!("Failed to serialize JSON: {}", e)))?;
+
+            output.push_str(&line);
+            output.push('\n');
+        }
+
+        for file in Self::json_files(files, context) {
+            let line = JsonLine {
+                schema_version: JSON_SCHEMA_VERSION,
+                file,
+            };
+
+            let line = serde_json::to_string(&line)
+                .map_err(|e| SwarmError::Other(format!("Failed to serialize JSON: {}", e)))?;
+
+            output.push_str(&line);
+            output.push('\n');
+        }
+
+        Ok(output)
+    }
+
+    fn json_files<'f>(files: &'f [OutputFile], context: &OutputContext) -> Vec<JsonFile<'f>> {
+        files.iter()
+            .map(|file| {
+                let content = file.text();
+
+                JsonFile {
+                    path: &file.path,
+                    variant: file.variant.as_deref(),
+                    language: Some(Self::fence_language(&file.path)).filter(|language| !language.is_empty()),
+                    size: content.len(),
+                    lines: content.lines().count(),
+                    tokens: estimate_tokens(&content),
+                    git_status: Self::git_status(&file.path, context),
+                    content,
+                }
+            })
+            .collect()
+    }
+
+    fn format_xml(files: &[OutputFile], context: &OutputContext) -> SwarmResult<String> {
         let mut output = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<files>\n");
 
-        for (path, content) in files {
-            let escaped_path = Self::escape_xml(path);
-            let escaped_content = Self::escape_xml(content);
+        if let Some(ref tree) = context.tree {
+            writeln!(output, "  <tree><![CDATA[{}]]></tree>", Self::escape_cdata(tree))
+                .map_err(|e| SwarmError::Other(format!("Failed to write: {}", e)))?;
+        }
+
+        for file in files {
+            let escaped_path = Self::escape_xml(&file.label());
+            let escaped_content = Self::escape_cdata(&file.text());
 
             writeln!(output, "  <file>")
                 .map_err(|e| SwarmError::Other(format!("Failed to write: {}", e)))?;
@@ -104,6 +349,233 @@ impl OutputFormat {
         Ok(output)
     }
 
+    fn format_documents(files: &[OutputFile], context: &OutputContext) -> SwarmResult<String> {
+        let mut output = String::from("<documents>\n");
+
+        if let Some(ref tree) = context.tree {
+            writeln!(output, "<directory_tree><![CDATA[{}]]></directory_tree>", Self::escape_cdata(tree))
+                .map_err(|e| SwarmError::Other(format!("Failed to write: {}", e)))?;
+        }
+
+        for (index, file) in files.iter().enumerate() {
+            writeln!(output, "<document index=\"{}\">", index + 1)
+                .map_err(|e| SwarmError::Other(format!("Failed to write: {}", e)))?;
+            writeln!(output, "<source>{}</source>", Self::escape_xml(&file.label()))
+                .map_err(|e| SwarmError::Other(format!("Failed to write: {}", e)))?;
+            writeln!(output, "<document_content><![CDATA[{}]]></document_content>", Self::escape_cdata(&file.text()))
+                .map_err(|e| SwarmError::Other(format!("Failed to write content: {}", e)))?;
+            writeln!(output, "</document>")
+                .map_err(|e| SwarmError::Other(format!("Failed to write: {}", e)))?;
+        }
+
+        writeln!(output, "</documents>")
+            .map_err(|e| SwarmError::Other(format!("Failed to write: {}", e)))?;
+
+        Ok(output)
+    }
+
+    fn format_html(files: &[OutputFile], context: &OutputContext) -> SwarmResult<String> {
+        let root = context.root.clone().or_else(|| Self::common_root(files));
+
+        let title = root.as_ref()
+            .and_then(|root| root.file_name())
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| "Context".to_string());
+
+        let mut total_lines = 0;
+        let mut total_tokens = 0;
+
+        let html_files: Vec<HtmlFile> = files.iter()
+            .map(|file| {
+                let language = Language::from_path(Path::new(&file.path));
+                let lines = file.content.lines().count();
+                let tokens = estimate_tokens(&file.content);
+
+                total_lines += lines;
+                total_tokens += tokens;
+
+                let mut meta = Vec::new();
+
+                if let Some(language) = language {
+                    meta.push(language.name().to_string());
+                }
+
+                meta.push(format!("{} lines", lines));
+                meta.push(format!("{} tokens", tokens));
+
+                if let Some(git_status) = Self::git_status(&file.path, context) {
+                    meta.push(git_status.to_string());
+                }
+
+                HtmlFile {
+                    meta,
+                    path: file.label_with(&Self::relative_path(root.as_deref(), &file.path)),
+                    body: highlight(&file.content, language),
+                    numbers: file.numbers.as_ref().map(|numbers| numbers.numbers().to_vec()),
+                }
+            })
+            .collect();
+
+        let summary = format!("{} files / {} lines / {} tokens", files.len(), total_lines, total_tokens);
+
+        Ok(html::render_page(&title, &summary, context.tree.as_deref(), &html_files))
+    }
+
+    fn format_template(files: &[OutputFile], context: &OutputContext) -> SwarmResult<String> {
+        let template = context.template.as_ref()
+            .ok_or_else(|| SwarmError::Config("No output template selected".into()))?;
+
+        let root = context.root.clone().or_else(|| Self::common_root(files));
+
+        let mut body = String::new();
+        let mut total_lines = 0;
+        let mut total_tokens = 0;
+
+        for (index, file) in files.iter().enumerate() {
+            let content = file.text();
+            let lines = content.lines().count();
+            let tokens = estimate_tokens(&content);
+
+            total_lines += lines;
+            total_tokens += tokens;
+
+            let relative_path = Self::relative_path(root.as_deref(), &file.path);
+            let git_status = Self::git_status(&file.path, context).unwrap_or_default();
+
+            body.push_str(&template::render(&template.file, |name| match name {
+                "content" => Some(content.to_string()),
+                "git_status" => Some(git_status.to_string()),
+                "index" => Some((index + 1).to_string()),
+                "language" => Some(Self::fence_language(&file.path).to_string()),
+                "lines" => Some(lines.to_string()),
+                "path" => Some(file.path.clone()),
+                "relative_path" => Some(relative_path.clone()),
+                "tokens" => Some(tokens.to_string()),
+                "tree" => Some(context.tree.clone().unwrap_or_default()),
+                "variant" => Some(file.variant.clone().unwrap_or_default()),
+                _ => None,
+            }));
+        }
+
+        let summary = |name: &str| match name {
+            "file_count" => Some(files.len().to_string()),
+            "total_lines" => Some(total_lines.to_string()),
+            "total_tokens" => Some(total_tokens.to_string()),
+            "tree" => Some(context.tree.clone().unwrap_or_default()),
+            _ => None,
+        };
+
+        let mut output = template::render(&template.header, summary);
+        output.push_str(&body);
+        output.push_str(&template::render(&template.footer, summary));
+
+        Ok(output)
+    }
+
+    fn common_root(files: &[OutputFile]) -> Option<PathBuf> {
+        let mut root: Option<PathBuf> = None;
+
+        for file in files {
+            let path = Path::new(&file.path).clean_path();
+            let parent = path.parent()?.to_path_buf();
+
+            root = Some(match root {
+                None => parent,
+                Some(current) => current
+                    .ancestors()
+                    .find(|ancestor| parent.starts_with(ancestor))
+                    .map(Path::to_path_buf)?,
+            });
+        }
+
+        root
+    }
+
+    fn relative_path(root: Option<&Path>, path: &str) -> String {
+        root
+            .and_then(|root| {
+                let path = Path::new(path).clean_path();
+                path.strip_prefix(root).ok().map(|relative| relative.display().to_string())
+            })
+            .unwrap_or_else(|| path.to_string())
+    }
+
+    // Files outside a repository have no status rather than "unmodified"
+    fn git_status(path: &str, context: &OutputContext) -> Option<&'static str> {
+        context.git
+            .filter(|git| git.is_in_repo())
+            .map(|git| git.get_status(&Path::new(path).clean_path()).name())
+    }
+
+    // The fence must be longer than any backtick run in the content
+    fn fence(content: &str) -> String {
+        let longest = content
+            .split(|c| c != '`')
+            .map(str::len)
+            .max()
+            .unwrap_or_default();
+
+        "`".repeat(longest.max(2) + 1)
+    }
+
+    fn fence_language(path: &str) -> &'static str {
+        let path = Path::new(path);
+
+        let name = path.file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or_default()
+            .to_lowercase();
+
+        let extension = path.extension()
+            .and_then(|extension| extension.to_str())
+            .unwrap_or_default()
+            .to_lowercase();
+
+        match (name.as_str(), extension.as_str()) {
+            ("dockerfile", _) => "dockerfile",
+            ("makefile" | "gnumakefile", _) | (_, "mk") => "makefile",
+            ("cmakelists.txt", _) | (_, "cmake") => "cmake",
+            (_, "bash" | "sh" | "zsh") => "bash",
+            (_, "bat" | "cmd") => "batch",
+            (_, "c" | "h") => "c",
+            (_, "cc" | "cpp" | "cxx" | "hh" | "hpp" | "hxx") => "cpp",
+            (_, "cs") => "csharp",
+            (_, "diff" | "patch") => "diff",
+            (_, "fish") => "fish",
+            (_, "go") => "go",
+            (_, "gradle" | "groovy") => "groovy",
+            (_, "graphql" | "gql") => "graphql",
+            (_, "hs") => "haskell",
+            (_, "ini" | "cfg" | "conf") => "ini",
+            (_, "java") => "java",
+            (_, "jsx") => "jsx",
+            (_, "kt" | "kts") => "kotlin",
+            (_, "less") => "less",
+            (_, "lua") => "lua",
+            (_, "m") => "objectivec",
+            (_, "nix") => "nix",
+            (_, "php") => "php",
+            (_, "pl" | "pm") => "perl",
+            (_, "ps1" | "psm1") => "powershell",
+            (_, "proto") => "protobuf",
+            (_, "r") => "r",
+            (_, "rb") => "ruby",
+            (_, "scala") => "scala",
+            (_, "scss") => "scss",
+            (_, "sql") => "sql",
+            (_, "svelte") => "svelte",
+            (_, "swift") => "swift",
+            (_, "tf" | "hcl") => "hcl",
+            (_, "ts" | "mts" | "cts") => "typescript",
+            (_, "tsx") => "tsx",
+            (_, "vue") => "vue",
+            (_, "xml" | "svg" | "xsd" | "xsl") => "xml",
+            _ => Language::from_path(path)
+                .map(|language| language.id())
+                .unwrap_or_default(),
+        }
+    }
+
     fn escape_xml(s: &str) -> String {
         s.replace('&', "&amp;")
             .replace('<', "&lt;")
@@ -111,4 +583,9 @@ impl OutputFormat {
             .replace('"', "&quot;")
             .replace('\'', "&apos;")
     }
+
+    // A CDATA section cannot contain "]]>", so it is split across two sections
+    fn escape_cdata(s: &str) -> String {
+        s.replace("]]>", "]]]]><![CDATA[>")
+    }
 }
@@ -0,0 +1,104 @@
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::model::error::{SwarmError, SwarmResult};
+use crate::model::options::Options;
+use crate::model::template;
+use crate::services::filesystem::gather::estimate_tokens;
+use crate::services::filesystem::git::GitService;
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Prompt {
+    pub name: String,
+
+    #[serde(default)]
+    pub preamble: String,
+
+    #[serde(default)]
+    pub postscript: String,
+}
+
+pub struct PromptContext<'a> {
+    pub branch: Option<&'a str>,
+    pub file_count: usize,
+    pub project: String,
+}
+
+impl<'a> PromptContext<'a> {
+    pub fn new(paths: &[String], git: Option<&'a GitService>, file_count: usize) -> Self {
+        let root = git
+            .and_then(|git| git.project_root())
+            .or_else(|| paths.first().map(|path| Path::new(path.trim())));
+
+        let project = root
+            .and_then(|root| root.file_name())
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        Self {
+            branch: git.and_then(|git| git.branch()),
+            file_count,
+            project,
+        }
+    }
+}
+
+impl Prompt {
+    pub fn selected(options: &Options) -> SwarmResult<Option<&Prompt>> {
+        let Some(ref name) = options.prompt else {
+            return Ok(None);
+        };
+
+        options.prompts
+            .iter()
+            .find(|prompt| prompt.name == *name)
+            .map(Some)
+            .ok_or_else(|| SwarmError::Config(format!("Prompt '{}' not found", name)))
+    }
+
+    pub fn wrap(&self, output: &str, context: &PromptContext) -> String {
+        let token_count = estimate_tokens(output);
+
+        let lookup = |name: &str| match name {
+            "branch" => Some(context.branch.unwrap_or_default().to_string()),
+            "file_count" => Some(context.file_count.to_string()),
+            "project" => Some(context.project.clone()),
+            "token_count" => Some(token_count.to_string()),
+            _ => None,
+        };
+
+        let mut wrapped = String::new();
+        let preamble = template::render(self.preamble.trim_end(), lookup);
+        let postscript = template::render(self.postscript.trim_end(), lookup);
+
+        if !preamble.is_empty() {
+            wrapped.push_str(&preamble);
+            wrapped.push_str("\n\n");
+        }
+
+        if postscript.is_empty() {
+            wrapped.push_str(output);
+        } else {
+            wrapped.push_str(output.trim_end());
+            wrapped.push_str("\n\n");
+            wrapped.push_str(&postscript);
+            wrapped.push('\n');
+        }
+
+        wrapped
+    }
+}
+
+pub fn apply_prompt(
+    output: String,
+    options: &Options,
+    paths: &[String],
+    git: Option<&GitService>,
+    file_count: usize,
+) -> SwarmResult<String> {
+    match Prompt::selected(options)? {
+        Some(prompt) => Ok(prompt.wrap(&output, &PromptContext::new(paths, git, file_count))),
+        None => Ok(output),
+    }
+}
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub enum SkeletonLevel {
+    Signatures,
+    #[default]
+    Outline,
+    Expanded,
+}
+
+impl SkeletonLevel {
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Signatures => "Signatures",
+            Self::Outline => "Outline",
+            Self::Expanded => "Expanded",
+        }
+    }
+
+    pub fn all() -> &'static [SkeletonLevel] {
+        &[
+            Self::Signatures,
+            Self::Outline,
+            Self::Expanded,
+        ]
+    }
+}
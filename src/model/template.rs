@@ -0,0 +1,124 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::constants::APP_NAME;
+use crate::model::error::{SwarmError, SwarmResult};
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum Section {
+    Header,
+    File,
+    Footer,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Template {
+    pub file: String,
+    pub footer: String,
+    pub header: String,
+}
+
+impl Template {
+    pub fn load(name: &str) -> SwarmResult<Self> {
+        let path = Self::resolve(name)
+            .ok_or_else(|| SwarmError::Config(format!("Template '{}' not found", name)))?;
+
+        let text = fs::read_to_string(&path)?;
+
+        Ok(Self::parse(&text))
+    }
+
+    pub fn parse(text: &str) -> Self {
+        let mut template = Self::default();
+        let mut section = Section::File;
+        let mut has_sections = false;
+
+        for line in text.split_inclusive('\n') {
+            let marker = match line.trim() {
+                "--- header ---" => Some(Section::Header),
+                "--- file ---" => Some(Section::File),
+                "--- footer ---" => Some(Section::Footer),
+                _ => None,
+            };
+
+            if let Some(marker) = marker {
+                section = marker;
+                has_sections = true;
+                continue;
+            }
+
+            match section {
+                Section::Header => template.header.push_str(line),
+                Section::File => template.file.push_str(line),
+                Section::Footer => template.footer.push_str(line),
+            }
+        }
+
+        if !has_sections {
+            template.file = text.to_string();
+        }
+
+        template
+    }
+
+    pub fn resolve(name: &str) -> Option<PathBuf> {
+        let path = Path::new(name);
+
+        if path.is_file() {
+            return Some(path.to_path_buf());
+        }
+
+        templates_dir()
+            .map(|dir| dir.join(name))
+            .filter(|path| path.is_file())
+    }
+
+    pub fn available() -> Vec<String> {
+        let Some(dir) = templates_dir() else {
+            return Vec::new();
+        };
+
+        let Ok(entries) = fs::read_dir(dir) else {
+            return Vec::new();
+        };
+
+        let mut names: Vec<String> = entries
+            .flatten()
+            .filter(|entry| entry.path().is_file())
+            .filter_map(|entry| entry.file_name().to_str().map(str::to_string))
+            .collect();
+
+        names.sort();
+        names
+    }
+}
+
+pub fn render(text: &str, lookup: impl Fn(&str) -> Option<String>) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find("{{") {
+        output.push_str(&rest[..start]);
+
+        let Some(length) = rest[start + 2..].find("}}") else {
+            rest = &rest[start..];
+            break;
+        };
+
+        let name = rest[start + 2..start + 2 + length].trim();
+
+        match lookup(name) {
+            Some(value) => output.push_str(&value),
+            None => output.push_str(&rest[start..start + 4 + length]),
+        }
+
+        rest = &rest[start + 4 + length..];
+    }
+
+    output.push_str(rest);
+    output
+}
+
+pub fn templates_dir() -> Option<PathBuf> {
+    dirs::data_local_dir().map(|dir| dir.join(APP_NAME.to_lowercase()).join("templates"))
+}
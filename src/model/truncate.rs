@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub enum TruncateUnit {
+    #[default]
+    Lines,
+    Tokens,
+    Bytes,
+}
+
+impl TruncateUnit {
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Lines => "Lines",
+            Self::Tokens => "Tokens",
+            Self::Bytes => "Bytes",
+        }
+    }
+
+    pub fn all() -> &'static [TruncateUnit] {
+        &[
+            Self::Lines,
+            Self::Tokens,
+            Self::Bytes,
+        ]
+    }
+
+    pub fn default_limit(&self) -> usize {
+        match self {
+            Self::Lines => 1000,
+            Self::Tokens => 10000,
+            Self::Bytes => 40000,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub enum TruncateMode {
+    #[default]
+    Excerpt,
+    Skeleton,
+}
+
+impl TruncateMode {
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Excerpt => "First and last lines",
+            Self::Skeleton => "Skeleton",
+        }
+    }
+
+    pub fn all() -> &'static [TruncateMode] {
+        &[
+            Self::Excerpt,
+            Self::Skeleton,
+        ]
+    }
+}
@@ -0,0 +1,209 @@
+use tree_sitter::Node;
+
+use crate::services::skeleton::generator::{node_text, parse};
+use crate::services::skeleton::language::Language;
+use crate::services::skeleton::query::{NodeCategory, Rules};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Import {
+    Module { level: usize, segments: Vec<String>, names: Vec<String> },
+    RustMod(String),
+    RustUse(Vec<String>),
+    Script(String),
+}
+
+pub fn extract_imports(content: &str, language: Language) -> Vec<Import> {
+    let mut imports = Vec::new();
+
+    if !matches!(language, Language::JavaScript | Language::Python | Language::Rust) {
+        return imports;
+    }
+
+    let Some(tree) = parse(content, language) else {
+        return imports;
+    };
+
+    let root = tree.root_node();
+    let source = content.as_bytes();
+
+    let Some(rules) = Rules::collect(root, source, language) else {
+        return imports;
+    };
+
+    collect_imports(&mut imports, root, source, language, &rules);
+
+    imports
+}
+
+fn collect_imports(
+    imports: &mut Vec<Import>,
+    node: Node,
+    source: &[u8],
+    language: Language,
+    rules: &Rules,
+) {
+    let mut cursor = node.walk();
+
+    for child in node.named_children(&mut cursor) {
+        if rules.is(child, NodeCategory::Import) {
+            match language {
+                Language::JavaScript => append_script_import(imports, child, source),
+                Language::Python => append_python_import(imports, child, source),
+                Language::Rust => append_rust_use(imports, child, source),
+                _ => {}
+            }
+
+            continue;
+        }
+
+        match child.kind() {
+            "export_statement" => append_script_import(imports, child, source),
+            "mod_item" if child.child_by_field_name("body").is_none() => {
+                if let Some(name) = child.child_by_field_name("name") {
+                    imports.push(Import::RustMod(node_text(name, source)));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn append_script_import(imports: &mut Vec<Import>, node: Node, source: &[u8]) {
+    let Some(specifier) = node.child_by_field_name("source") else {
+        return;
+    };
+
+    let text = node_text(specifier, source);
+    let specifier = text.trim_matches(['"', '\'', '`']);
+
+    if !specifier.is_empty() {
+        imports.push(Import::Script(specifier.to_string()));
+    }
+}
+
+fn append_python_import(imports: &mut Vec<Import>, node: Node, source: &[u8]) {
+    let text = node_text(node, source);
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    if let Some(modules) = text.strip_prefix("import ") {
+        for module in split_names(modules) {
+            imports.push(Import::Module {
+                level: 0,
+                segments: module.split('.').map(str::to_string).collect(),
+                names: Vec::new(),
+            });
+        }
+
+        return;
+    }
+
+    let Some(rest) = text.strip_prefix("from ") else {
+        return;
+    };
+
+    let Some((module, names)) = rest.split_once(" import ") else {
+        return;
+    };
+
+    let module = module.trim();
+    let level = module.chars().take_while(|&c| c == '.').count();
+
+    let segments = module[level..]
+        .split('.')
+        .filter(|segment| !segment.is_empty())
+        .map(str::to_string)
+        .collect();
+
+    let names = split_names(names.trim_matches(['(', ')', ' ']))
+        .into_iter()
+        .filter(|name| name != "*")
+        .collect();
+
+    imports.push(Import::Module { level, segments, names });
+}
+
+fn append_rust_use(imports: &mut Vec<Import>, node: Node, source: &[u8]) {
+    if node.kind() != "use_declaration" {
+        return;
+    }
+
+    let Some(argument) = node.child_by_field_name("argument") else {
+        return;
+    };
+
+    for path in expand_use_tree(&node_text(argument, source)) {
+        let segments: Vec<String> = path
+            .split("::")
+            .map(str::trim)
+            .filter(|segment| !segment.is_empty() && *segment != "*" && *segment != "self")
+            .map(str::to_string)
+            .collect();
+
+        if !segments.is_empty() {
+            imports.push(Import::RustUse(segments));
+        }
+    }
+}
+
+fn expand_use_tree(text: &str) -> Vec<String> {
+    let text = text.trim();
+
+    let Some(open) = text.find('{') else {
+        return vec![strip_alias(text).to_string()];
+    };
+
+    let prefix = text[..open].trim_end_matches(':').trim();
+    let close = text.rfind('}').unwrap_or(text.len());
+    let inner = &text[open + 1..close.max(open + 1)];
+
+    let mut paths = Vec::new();
+
+    for item in split_top_level(inner) {
+        for path in expand_use_tree(item) {
+            if prefix.is_empty() {
+                paths.push(path);
+            } else {
+                paths.push(format!("{}::{}", prefix, path));
+            }
+        }
+    }
+
+    paths
+}
+
+fn split_top_level(text: &str) -> Vec<&str> {
+    let mut items = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+
+    for (index, c) in text.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                items.push(&text[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+
+    items.push(&text[start..]);
+    items.retain(|item| !item.trim().is_empty());
+
+    items
+}
+
+fn split_names(text: &str) -> Vec<String> {
+    text.split(',')
+        .map(|name| strip_alias(name.trim()).to_string())
+        .filter(|name| !name.is_empty())
+        .collect()
+}
+
+fn strip_alias(text: &str) -> &str {
+    match text.split_once(" as ") {
+        Some((name, _)) => name.trim(),
+        None => text.trim(),
+    }
+}
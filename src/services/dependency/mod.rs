@@ -0,0 +1,5 @@
+pub mod imports;
+pub mod resolver;
+
+pub use imports::Import;
+pub use resolver::DependencyResolver;
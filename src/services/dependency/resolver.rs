@@ -0,0 +1,309 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use crate::model::error::SwarmResult;
+use crate::model::options::Options;
+use crate::services::skeleton::SkeletonGenerator;
+use crate::services::skeleton::generator::process_parallel;
+use crate::services::skeleton::language::Language;
+
+use super::imports::{extract_imports, Import};
+
+const SCRIPT_EXTENSIONS: &[&str] = &["js", "mjs", "cjs", "jsx", "ts", "tsx"];
+
+#[derive(Clone, Debug)]
+pub struct DependencyResolver {
+    roots: Vec<PathBuf>,
+}
+
+impl DependencyResolver {
+    pub fn new(roots: &[PathBuf]) -> Self {
+        Self {
+            roots: roots.iter().map(|root| normalize(root)).collect(),
+        }
+    }
+
+    pub fn expand(&self, files: &[PathBuf], depth: usize) -> Vec<PathBuf> {
+        let mut visited: HashSet<PathBuf> = files.iter().map(|file| normalize(file)).collect();
+        let mut frontier: Vec<PathBuf> = visited.iter().cloned().collect();
+        let mut found = Vec::new();
+
+        for _ in 0..depth {
+            let mut next = Vec::new();
+
+            for file in &frontier {
+                for dependency in self.dependencies(file) {
+                    if visited.insert(dependency.clone()) {
+                        next.push(dependency);
+                    }
+                }
+            }
+
+            if next.is_empty() {
+                break;
+            }
+
+            found.extend(next.iter().cloned());
+            frontier = next;
+        }
+
+        found.sort();
+        found
+    }
+
+    pub fn dependents(&self, target: &Path, options: &Options) -> SwarmResult<Vec<PathBuf>> {
+        let target = normalize(target);
+
+        let roots: Vec<String> = self.roots.iter().map(|root| root.display().to_string()).collect();
+        let files = SkeletonGenerator::new().resolve_files(&roots, options, None, None)?;
+
+        let mut dependents = Vec::new();
+
+        process_parallel(
+            &files,
+            |file| {
+                let file = normalize(file);
+
+                if file.starts_with(&target) {
+                    return None;
+                }
+
+                self.dependencies(&file)
+                    .iter()
+                    .any(|dependency| dependency.starts_with(&target))
+                    .then_some(file)
+            },
+            &mut |_, _| {},
+            &mut |file| dependents.push(file),
+        );
+
+        Ok(dependents)
+    }
+
+    pub fn dependencies(&self, path: &Path) -> Vec<PathBuf> {
+        let Some(language) = Language::from_path(path) else {
+            return Vec::new();
+        };
+
+        let Ok(content) = fs::read_to_string(path) else {
+            return Vec::new();
+        };
+
+        let path = normalize(path);
+        let mut dependencies = Vec::new();
+
+        for import in extract_imports(&content, language) {
+            let resolved = match import {
+                Import::Module { level, segments, names } => {
+                    self.resolve_python(&path, level, &segments, &names)
+                }
+                Import::RustMod(name) => self.resolve_rust_mod(&path, &name),
+                Import::RustUse(segments) => self.resolve_rust_use(&path, &segments),
+                Import::Script(specifier) => self.resolve_script(&path, &specifier),
+            };
+
+            for dependency in resolved {
+                if dependency != path && !dependencies.contains(&dependency) {
+                    dependencies.push(dependency);
+                }
+            }
+        }
+
+        dependencies
+    }
+
+    fn resolve_python(
+        &self,
+        path: &Path,
+        level: usize,
+        segments: &[String],
+        names: &[String],
+    ) -> Vec<PathBuf> {
+        let Some(directory) = path.parent() else {
+            return Vec::new();
+        };
+
+        let bases: Vec<PathBuf> = if level > 0 {
+            directory.ancestors().nth(level - 1).map(Path::to_path_buf).into_iter().collect()
+        } else {
+            directory
+                .ancestors()
+                .take_while(|ancestor| self.contains(ancestor))
+                .map(Path::to_path_buf)
+                .collect()
+        };
+
+        for base in bases {
+            let module = segments.iter().fold(base, |acc, segment| acc.join(segment));
+            let mut resolved = Vec::new();
+
+            for name in names {
+                if let Some(file) = self.python_module(&module.join(name)) {
+                    resolved.push(file);
+                }
+            }
+
+            if !segments.is_empty()
+                && let Some(file) = self.python_module(&module)
+            {
+                resolved.push(file);
+            }
+
+            if !resolved.is_empty() {
+                return resolved;
+            }
+        }
+
+        Vec::new()
+    }
+
+    fn python_module(&self, module: &Path) -> Option<PathBuf> {
+        let candidates = [module.with_extension("py"), module.join("__init__.py")];
+        self.first_file(&candidates)
+    }
+
+    fn resolve_rust_mod(&self, path: &Path, name: &str) -> Vec<PathBuf> {
+        let directory = rust_module_dir(path);
+        let candidates = [directory.join(format!("{}.rs", name)), directory.join(name).join("mod.rs")];
+
+        self.first_file(&candidates).into_iter().collect()
+    }
+
+    fn resolve_rust_use(&self, path: &Path, segments: &[String]) -> Vec<PathBuf> {
+        let mut base = match segments[0].as_str() {
+            "crate" => match rust_crate_root(path) {
+                Some(root) => root,
+                None => return Vec::new(),
+            },
+            "self" => rust_module_dir(path),
+            "super" => rust_module_dir(path).parent().map(Path::to_path_buf).unwrap_or_default(),
+            _ => return Vec::new(),
+        };
+
+        let mut rest = &segments[1..];
+
+        while rest.first().is_some_and(|segment| segment == "super") {
+            base = base.parent().map(Path::to_path_buf).unwrap_or_default();
+            rest = &rest[1..];
+        }
+
+        for length in (1..=rest.len()).rev() {
+            let module = rest[..length].iter().fold(base.clone(), |acc, segment| acc.join(segment));
+            let candidates = [module.with_extension("rs"), module.join("mod.rs")];
+
+            if let Some(file) = self.first_file(&candidates) {
+                return vec![file];
+            }
+        }
+
+        let candidates = [
+            base.with_extension("rs"),
+            base.join("mod.rs"),
+            base.join("lib.rs"),
+            base.join("main.rs"),
+        ];
+
+        self.first_file(&candidates).into_iter().collect()
+    }
+
+    fn resolve_script(&self, path: &Path, specifier: &str) -> Vec<PathBuf> {
+        if !specifier.starts_with('.') {
+            return Vec::new();
+        }
+
+        let Some(directory) = path.parent() else {
+            return Vec::new();
+        };
+
+        let target = normalize(&directory.join(specifier));
+        let mut candidates = vec![target.clone()];
+
+        for extension in SCRIPT_EXTENSIONS {
+            let mut name = target.clone().into_os_string();
+            name.push(format!(".{}", extension));
+            candidates.push(PathBuf::from(name));
+        }
+
+        for extension in SCRIPT_EXTENSIONS {
+            candidates.push(target.join(format!("index.{}", extension)));
+        }
+
+        self.first_file(&candidates).into_iter().collect()
+    }
+
+    fn first_file(&self, candidates: &[PathBuf]) -> Option<PathBuf> {
+        candidates
+            .iter()
+            .map(|candidate| normalize(candidate))
+            .find(|candidate| candidate.is_file() && self.contains(candidate))
+    }
+
+    fn contains(&self, path: &Path) -> bool {
+        self.roots.iter().any(|root| path.starts_with(root))
+    }
+}
+
+fn rust_module_dir(path: &Path) -> PathBuf {
+    let directory = path.parent().map(Path::to_path_buf).unwrap_or_default();
+
+    match path.file_stem().and_then(|stem| stem.to_str()) {
+        Some("lib" | "main" | "mod") | None => directory,
+        Some(stem) => directory.join(stem),
+    }
+}
+
+// `crate::` starts at the directory of the target root the file belongs to,
+// taken from the nearest Cargo.toml and its `[lib]` and `[[bin]]` paths
+fn rust_crate_root(path: &Path) -> Option<PathBuf> {
+    let directory = path.parent()?;
+    let Some(package) = directory.ancestors().find(|ancestor| ancestor.join("Cargo.toml").is_file()) else {
+        return directory.ancestors().find(|ancestor| ancestor.ends_with("src")).map(Path::to_path_buf);
+    };
+
+    let manifest = fs::read_to_string(package.join("Cargo.toml")).ok()?
+        .parse::<toml::Table>().ok()?;
+
+    let target_path = |target: &toml::Value| target.get("path")
+        .and_then(toml::Value::as_str)
+        .map(|path| package.join(path));
+
+    let mut roots = vec![
+        manifest.get("lib").and_then(target_path).unwrap_or_else(|| package.join("src/lib.rs")),
+        package.join("src/main.rs"),
+    ];
+
+    if let Some(bins) = manifest.get("bin").and_then(toml::Value::as_array) {
+        roots.extend(bins.iter().filter_map(target_path));
+    }
+
+    // Binaries found in src/bin are roots of their own
+    let mut directories: Vec<PathBuf> = roots.iter()
+        .filter_map(|root| root.parent().map(Path::to_path_buf))
+        .collect();
+
+    directories.push(package.join("src/bin"));
+
+    directories.into_iter()
+        .filter(|root| directory.starts_with(root))
+        .max_by_key(|root| root.components().count())
+        .or_else(|| Some(package.join("src")))
+}
+
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !normalized.pop() {
+                    normalized.push(component);
+                }
+            }
+            _ => normalized.push(component),
+        }
+    }
+
+    normalized
+}
@@ -1,21 +1,149 @@
+use std::collections::HashSet;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use ignore::WalkBuilder;
 
-use crate::app::state::search::{Command, ParsedQuery};
+use crate::app::state::search::{Command, FileMetadata, ParsedQuery};
 use crate::model::error::{SwarmError, SwarmResult};
 use crate::model::options::Options;
+use crate::model::output::{OutputContext, OutputFile};
 use crate::model::path::PathExtensions;
+use crate::model::prompt::apply_prompt;
+use crate::model::truncate::TruncateMode;
+use crate::services::skeleton::generator::{extract_skeleton, SkeletonConfig};
+use crate::services::skeleton::{Language, SkeletonGenerator};
+use crate::services::symbol::{extract_symbols, SymbolQuery};
+use crate::services::tree::TreeGenerator;
 
 use super::filter::{GlobPathFilter, PathFilter};
 use super::git::GitService;
+use super::minify::{minify_lines, MinifyConfig};
+use super::numbering::{LineNumbers, SourceLines};
+use super::truncate::{excerpt, TruncateConfig};
 
 #[derive(Clone, Debug)]
 pub struct GatherStats {
     pub line_count: usize,
     pub token_count: usize,
+    pub tokens_saved: usize,
+}
+
+impl GatherStats {
+    pub fn summary(&self) -> String {
+        if self.tokens_saved > 0 {
+            format!("{} lines / {} tokens ({} saved)", self.line_count, self.token_count, self.tokens_saved)
+        } else {
+            format!("{} lines / {} tokens", self.line_count, self.token_count)
+        }
+    }
+}
+
+struct ContentTransform {
+    line_numbers: bool,
+    minify: MinifyConfig,
+    skeleton: SkeletonConfig,
+    tokens_saved: usize,
+    truncate: TruncateConfig,
+}
+
+impl ContentTransform {
+    fn new(options: &Options, query: Option<&ParsedQuery>) -> Self {
+        let line_numbers = options.line_numbers || query.is_some_and(|q| q.has_command(Command::LineNumbers));
+
+        let mut skeleton = SkeletonConfig::from_options(options);
+        skeleton.line_numbers = line_numbers;
+
+        Self {
+            line_numbers,
+            minify: MinifyConfig::from_options(options),
+            skeleton,
+            tokens_saved: 0,
+            truncate: TruncateConfig::new(options, query),
+        }
+    }
+
+    fn apply(&mut self, path: &Path, variant: Option<&str>, content: String) -> OutputFile {
+        let file = |content: String, numbers: Option<LineNumbers>| {
+            let file = OutputFile::new(path.display().to_string(), content).with_numbers(numbers);
+
+            match variant {
+                Some(variant) => file.with_variant(variant),
+                None => file,
+            }
+        };
+
+        let truncated = self.truncate.exceeds(&content);
+
+        if truncated
+            && self.truncate.mode == TruncateMode::Skeleton
+            && let Some(skeleton) = self.skeleton(path, &content)
+        {
+            let variant = variant.map_or("skeleton".to_string(), |variant| format!("{}, skeleton", variant));
+            return skeleton.with_variant(variant);
+        }
+
+        if !truncated && !self.minify.is_enabled() {
+            let numbers = self.line_numbers.then(|| LineNumbers::sequential(&content));
+            return file(content, numbers);
+        }
+
+        let lines = if self.minify.is_enabled() {
+            self.minify(path, &content)
+        } else {
+            content
+                .split_inclusive('\n')
+                .enumerate()
+                .map(|(index, line)| (index + 1, line.to_string()))
+                .collect()
+        };
+
+        let lines = if truncated {
+            excerpt(lines, self.truncate.excerpt)
+        } else {
+            lines.into_iter().map(|(number, line)| (Some(number), line)).collect()
+        };
+
+        let numbers = self.line_numbers.then(|| {
+            LineNumbers::new(lines.iter().map(|(number, _)| *number).collect(), content.lines().count())
+        });
+
+        file(lines.into_iter().map(|(_, line)| line).collect(), numbers)
+    }
+
+    fn apply_extract(&mut self, path: &Path, extract: SourceLines, source: &str) -> (String, Option<LineNumbers>) {
+        if !self.minify.is_enabled() {
+            let numbers = self.line_numbers.then(|| extract.numbers(source.lines().count()));
+            return (extract.text(), numbers);
+        }
+
+        let lines = self.minify(path, &extract.text());
+
+        let numbers = self.line_numbers.then(|| {
+            let rows = extract.rows();
+            let numbers = lines.iter().map(|(number, _)| rows[number - 1].map(|row| row + 1)).collect();
+            LineNumbers::new(numbers, source.lines().count())
+        });
+
+        (lines.into_iter().map(|(_, line)| line).collect(), numbers)
+    }
+
+    fn skeleton(&self, path: &Path, content: &str) -> Option<OutputFile> {
+        let skeleton = extract_skeleton(content, Language::from_path(path)?, &self.skeleton)?;
+        let numbers = self.line_numbers.then(|| skeleton.numbers(content.lines().count()));
+
+        Some(OutputFile::new(path.display().to_string(), skeleton.text()).with_numbers(numbers))
+    }
+
+    fn minify(&mut self, path: &Path, content: &str) -> Vec<(usize, String)> {
+        let lines = minify_lines(content, Language::from_path(path), &self.minify);
+
+        let minified: String = lines.iter().map(|(_, line)| line.as_str()).collect();
+        self.tokens_saved += estimate_tokens(content).saturating_sub(estimate_tokens(&minified));
+
+        lines
+    }
 }
 
 #[derive(Clone)]
@@ -41,6 +169,88 @@ impl GatherService {
         let mut files = Vec::new();
 
         let include_diff = query.is_some_and(|q| q.has_command(Command::Diff));
+        let mut transform = ContentTransform::new(options, query);
+
+        let symbols: Vec<SymbolQuery> = query
+            .map(|q| q.symbols.iter().filter_map(|symbol| SymbolQuery::parse(symbol)).collect())
+            .unwrap_or_default();
+
+        let selected = Self::resolve_files(paths, &filter)?;
+
+        for path in &selected {
+            if symbols.is_empty() {
+                Self::collect_file(path, &mut files, git_service, include_diff, &mut transform);
+            } else {
+                Self::collect_symbols(path, &mut files, &symbols, &mut transform);
+            }
+        }
+
+        Self::format_output(&files, &selected, paths, options, git_service, query, transform.tokens_saved)
+    }
+
+    pub fn gather_mixed(
+        &self,
+        full_paths: &[String],
+        roots: &[String],
+        options: &Options,
+        git_service: Option<&GitService>,
+        query: Option<&ParsedQuery>,
+    ) -> SwarmResult<(String, GatherStats)> {
+        let filter: Arc<dyn PathFilter> = Arc::new(GlobPathFilter::from_options(options)?);
+        let mut files = Vec::new();
+
+        let include_diff = query.is_some_and(|q| q.has_command(Command::Diff));
+        let mut transform = ContentTransform::new(options, query);
+        let full_files = Self::resolve_files(full_paths, &filter)?;
+
+        for path in &full_files {
+            Self::collect_file(path, &mut files, git_service, include_diff, &mut transform);
+        }
+
+        let mut skeleton_options = options.clone();
+        skeleton_options.line_numbers = transform.line_numbers;
+
+        let mut selected = full_files.clone();
+        let full_files: HashSet<PathBuf> = full_files.into_iter().collect();
+        let skeletons = SkeletonGenerator::new().collect(roots, &skeleton_options)?;
+
+        for mut skeleton in skeletons {
+            let path = Path::new(&skeleton.path).clean_path();
+
+            if full_files.contains(&path) {
+                continue;
+            }
+
+            skeleton.path = path.display().to_string();
+            files.push(skeleton.with_variant("skeleton"));
+            selected.push(path);
+        }
+
+        selected.sort();
+
+        Self::format_output(&files, &selected, roots, options, git_service, query, transform.tokens_saved)
+    }
+
+    pub fn matching_files(
+        &self,
+        paths: &[String],
+        options: &Options,
+        git_service: Option<&GitService>,
+        query: &ParsedQuery,
+    ) -> SwarmResult<Vec<String>> {
+        let filter: Arc<dyn PathFilter> = Arc::new(GlobPathFilter::from_options(options)?);
+
+        let files = Self::resolve_files(paths, &filter)?
+            .into_iter()
+            .filter(|path| matches_query(path, query, git_service))
+            .map(|path| path.display().to_string())
+            .collect();
+
+        Ok(files)
+    }
+
+    fn resolve_files(paths: &[String], filter: &Arc<dyn PathFilter>) -> SwarmResult<Vec<PathBuf>> {
+        let mut files = Vec::new();
 
         for path_str in paths {
             let path = Path::new(path_str.trim());
@@ -51,21 +261,44 @@ impl GatherService {
             }
 
             if clean_path.is_file() {
-                Self::collect_file(&clean_path, &mut files, git_service, include_diff);
+                files.push(clean_path);
             } else if clean_path.is_dir() {
-                Self::collect_directory(&clean_path, &mut files, &filter, git_service, include_diff)?;
+                Self::collect_directory(&clean_path, &mut files, filter)?;
             }
         }
 
+        Ok(files)
+    }
+
+    fn format_output(
+        files: &[OutputFile],
+        selected: &[PathBuf],
+        paths: &[String],
+        options: &Options,
+        git_service: Option<&GitService>,
+        query: Option<&ParsedQuery>,
+        tokens_saved: usize,
+    ) -> SwarmResult<(String, GatherStats)> {
         let output_format = query
             .and_then(|q| q.format_override)
             .unwrap_or(options.output_format);
 
-        let output = output_format.format(&files)?;
+        let mut context = OutputContext::new(output_format, options, git_service)?;
+
+        context.tree = TreeGenerator::generate_embedded(
+            selected,
+            git_service.and_then(|git| git.repo_root()),
+            options.embed_tree,
+            options,
+        )?;
+
+        let output = output_format.format_with(files, &context)?;
+        let output = apply_prompt(output, options, paths, git_service, files.len())?;
 
         let stats = GatherStats {
             line_count: output.lines().count(),
             token_count: estimate_tokens(&output),
+            tokens_saved,
         };
 
         Ok((output, stats))
@@ -73,9 +306,10 @@ impl GatherService {
 
     fn collect_file(
         path: &Path,
-        files: &mut Vec<(String, String)>,
+        files: &mut Vec<OutputFile>,
         git_service: Option<&GitService>,
         include_diff: bool,
+        transform: &mut ContentTransform,
     ) {
         let current_content = match fs::read_to_string(path) {
             Ok(c) => c,
@@ -88,29 +322,42 @@ impl GatherService {
 
                 if status.has_diff() {
                     if let Some(original) = git.get_original_content(path) {
-                        files.push((
-                            format!("{} (original)", path.display()),
-                            original,
-                        ));
-                        files.push((
-                            format!("{} (modified)", path.display()),
-                            current_content,
-                        ));
+                        files.push(transform.apply(path, Some("original"), original));
+                        files.push(transform.apply(path, Some("modified"), current_content));
                         return;
                     }
                 }
             }
         }
 
-        files.push((path.display().to_string(), current_content));
+        files.push(transform.apply(path, None, current_content));
+    }
+
+    fn collect_symbols(
+        path: &Path,
+        files: &mut Vec<OutputFile>,
+        symbols: &[SymbolQuery],
+        transform: &mut ContentTransform,
+    ) {
+        let Some(language) = Language::from_path(path) else {
+            return;
+        };
+
+        let content = match fs::read_to_string(path) {
+            Ok(c) => c,
+            Err(_) => return,
+        };
+
+        for (name, source) in extract_symbols(&content, language, symbols) {
+            let (source, numbers) = transform.apply_extract(path, source, &content);
+            files.push(OutputFile::new(path.display().to_string(), source).with_variant(name).with_numbers(numbers));
+        }
     }
 
     fn collect_directory(
         directory: &Path,
-        files: &mut Vec<(String, String)>,
+        files: &mut Vec<PathBuf>,
         filter: &Arc<dyn PathFilter>,
-        git_service: Option<&GitService>,
-        include_diff: bool,
     ) -> SwarmResult<()> {
         let walker = Self::create_walker(directory, filter);
 
@@ -121,7 +368,7 @@ impl GatherService {
 
             if entry.file_type().is_some_and(|file_type| file_type.is_file())
                 && filter.should_include(entry.path()) {
-                    Self::collect_file(entry.path(), files, git_service, include_diff);
+                    files.push(entry.path().to_path_buf());
                 }
         }
 
@@ -151,6 +398,24 @@ impl Default for GatherService {
     }
 }
 
+pub fn matches_query(path: &Path, query: &ParsedQuery, git_service: Option<&GitService>) -> bool {
+    if query.is_empty() {
+        return true;
+    }
+
+    let name = path.file_name_string().unwrap_or_default();
+    let path_str = path.to_string_lossy();
+    let git_status = git_service.map(|git| git.get_status(&path.clean_path()));
+
+    let metadata = if query.needs_metadata() {
+        FileMetadata::from_path(path, query.needs_content())
+    } else {
+        None
+    };
+
+    query.matches_full(&name, &path_str, git_status, false, metadata.as_ref())
+}
+
 pub fn estimate_tokens(text: &str) -> usize {
     if text.is_empty() {
         return 0;
@@ -20,10 +20,25 @@ impl GitStatus {
     pub fn has_diff(&self) -> bool {
         matches!(self, Self::Modified | Self::Added | Self::Staged | Self::Renamed)
     }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Unmodified => "unmodified",
+            Self::Added => "added",
+            Self::Conflicted => "conflicted",
+            Self::Deleted => "deleted",
+            Self::Modified => "modified",
+            Self::Renamed => "renamed",
+            Self::Staged => "staged",
+            Self::Untracked => "untracked",
+        }
+    }
 }
 
 #[derive(Clone)]
 pub struct GitService {
+    branch: Option<String>,
+    path: Option<PathBuf>,
     statuses: HashMap<PathBuf, GitStatus>,
     repo_root: Option<PathBuf>,
 }
@@ -37,12 +52,16 @@ impl Default for GitService {
 impl GitService {
     pub fn new() -> Self {
         Self {
+            branch: None,
+            path: None,
             statuses: HashMap::new(),
             repo_root: None,
         }
     }
 
     pub fn refresh(&mut self, path: &Path) {
+        self.branch = None;
+        self.path = Some(path.to_path_buf());
         self.statuses.clear();
         self.repo_root = None;
 
@@ -61,6 +80,10 @@ impl GitService {
 
         self.repo_root = Some(workdir.clone());
 
+        self.branch = repo.head()
+            .ok()
+            .and_then(|head| head.shorthand().map(str::to_string));
+
         let mut opts = StatusOptions::new();
 
         opts.include_untracked(true)
@@ -118,6 +141,19 @@ impl GitService {
         String::from_utf8(blob.content().to_vec()).ok()
     }
 
+    pub fn branch(&self) -> Option<&str> {
+        self.branch.as_deref()
+    }
+
+    pub fn repo_root(&self) -> Option<&Path> {
+        self.repo_root.as_deref()
+    }
+
+    // The repository root, or the refreshed path itself outside a repository
+    pub fn project_root(&self) -> Option<&Path> {
+        self.repo_root.as_deref().or(self.path.as_deref())
+    }
+
     pub fn is_in_repo(&self) -> bool {
         self.repo_root.is_some()
     }
@@ -0,0 +1,187 @@
+use std::collections::HashMap;
+
+use tree_sitter::Node;
+
+use crate::model::minify::IndentStyle;
+use crate::model::options::Options;
+use crate::services::skeleton::generator::parse;
+use crate::services::skeleton::language::Language;
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MinifyConfig {
+    pub blank_lines: bool,
+    pub comments: bool,
+    pub indent: IndentStyle,
+    pub trailing_whitespace: bool,
+}
+
+impl MinifyConfig {
+    pub fn from_options(options: &Options) -> Self {
+        Self {
+            blank_lines: options.minify_blank_lines,
+            comments: options.minify_comments,
+            indent: options.minify_indent,
+            trailing_whitespace: options.minify_trailing_whitespace,
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.blank_lines || self.comments || self.trailing_whitespace || self.indent != IndentStyle::Keep
+    }
+}
+
+pub fn minify(content: &str, language: Option<Language>, config: &MinifyConfig) -> String {
+    minify_lines(content, language, config)
+        .into_iter()
+        .map(|(_, line)| line)
+        .collect()
+}
+
+// Returns the remaining lines paired with their 1-based line number in the
+// original content, so line numbers survive the transformation.
+pub fn minify_lines(content: &str, language: Option<Language>, config: &MinifyConfig) -> Vec<(usize, String)> {
+    let mut lines: Vec<(usize, String)> = content
+        .split_inclusive('\n')
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.to_string()))
+        .collect();
+
+    if let Some(language) = language.filter(|_| config.comments) {
+        strip_comments(&mut lines, content, language);
+    }
+
+    if config.trailing_whitespace {
+        for (_, line) in &mut lines {
+            let body_length = line.trim_end_matches(['\r', '\n']).len();
+            *line = format!("{}{}", line[..body_length].trim_end(), &line[body_length..]);
+        }
+    }
+
+    if config.indent != IndentStyle::Keep {
+        normalize_indent(&mut lines, config.indent);
+    }
+
+    if config.blank_lines {
+        let mut previous_blank = false;
+
+        lines.retain(|(_, line)| {
+            let blank = line.trim().is_empty();
+            let keep = !(blank && previous_blank);
+
+            previous_blank = blank;
+            keep
+        });
+    }
+
+    lines
+}
+
+fn strip_comments(lines: &mut Vec<(usize, String)>, content: &str, language: Language) {
+    let Some(tree) = parse(content, language) else {
+        return;
+    };
+
+    let mut removed = vec![false; content.len()];
+    let mut has_comments = false;
+
+    collect_comments(tree.root_node(), content, &mut removed, &mut has_comments);
+
+    if !has_comments {
+        return;
+    }
+
+    let mut offset = 0;
+
+    lines.retain_mut(|(_, line)| {
+        let start = offset;
+        offset += line.len();
+
+        let body_length = line.trim_end_matches(['\r', '\n']).len();
+
+        if !removed[start..start + body_length].contains(&true) {
+            return true;
+        }
+
+        let kept: String = line[..body_length]
+            .char_indices()
+            .filter(|(index, _)| !removed[start + index])
+            .map(|(_, c)| c)
+            .collect();
+
+        // Lines that only held a comment are dropped entirely
+        if kept.trim().is_empty() {
+            return false;
+        }
+
+        *line = format!("{}{}", kept.trim_end(), &line[body_length..]);
+        true
+    });
+}
+
+fn collect_comments(node: Node, content: &str, removed: &mut [bool], has_comments: &mut bool) {
+    if matches!(node.kind(), "comment" | "line_comment" | "block_comment") {
+        let range = node.byte_range();
+
+        if range.start == 0 && content.starts_with("#!") {
+            return;
+        }
+
+        removed[range].fill(true);
+        *has_comments = true;
+
+        return;
+    }
+
+    let mut cursor = node.walk();
+
+    for child in node.children(&mut cursor) {
+        collect_comments(child, content, removed, has_comments);
+    }
+}
+
+fn normalize_indent(lines: &mut [(usize, String)], style: IndentStyle) {
+    let Some(unit) = indent_unit(lines) else {
+        return;
+    };
+
+    let level = match style {
+        IndentStyle::Tabs => "\t",
+        _ => " ",
+    };
+
+    for (_, line) in lines {
+        let spaces = leading_spaces(line);
+
+        if spaces == 0 || line.trim().is_empty() {
+            continue;
+        }
+
+        *line = format!("{}{}{}", level.repeat(spaces / unit), " ".repeat(spaces % unit), &line[spaces..]);
+    }
+}
+
+// The indent unit is the most common increase in indentation between
+// consecutive lines, which ignores one-off alignment such as " * " comments.
+fn indent_unit(lines: &[(usize, String)]) -> Option<usize> {
+    let mut steps: HashMap<usize, usize> = HashMap::new();
+    let mut previous = 0;
+
+    for (_, line) in lines.iter().filter(|(_, line)| !line.trim().is_empty()) {
+        let spaces = leading_spaces(line);
+
+        if spaces > previous {
+            *steps.entry(spaces - previous).or_default() += 1;
+        }
+
+        previous = spaces;
+    }
+
+    steps
+        .into_iter()
+        .max_by(|(a_step, a_count), (b_step, b_count)| a_count.cmp(b_count).then(b_step.cmp(a_step)))
+        .map(|(step, _)| step)
+}
+
+fn leading_spaces(line: &str) -> usize {
+    line.bytes().take_while(|&byte| byte == b' ').count()
+}
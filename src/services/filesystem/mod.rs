@@ -1,6 +1,9 @@
 pub mod filter;
 pub mod gather;
 pub mod git;
+pub mod minify;
+pub mod numbering;
+pub mod truncate;
 
 pub use filter::{AlwaysIncludeFilter, CompositeFilter, GlobPathFilter, PathFilter};
 pub use gather::{GatherService, GatherStats};
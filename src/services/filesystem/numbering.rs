@@ -0,0 +1,149 @@
+use std::fmt::Write;
+
+// Text built line by line along with the source row each line was taken from,
+// so skeletons and extracts can be numbered without matching text back.
+// Rows are zero based, lines without a row are printed unnumbered.
+#[derive(Clone, Debug, Default)]
+pub struct SourceLines {
+    lines: Vec<(Option<usize>, String)>,
+    open: bool,
+}
+
+impl SourceLines {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Later lines of multi-line text come from the rows that follow `row`
+    pub fn line(&mut self, row: impl Into<Option<usize>>, text: impl AsRef<str>) {
+        let row = row.into();
+
+        for (index, text) in text.as_ref().split('\n').enumerate() {
+            let row = row.filter(|_| !text.trim().is_empty()).map(|row| row + index);
+            self.lines.push((row, text.to_string()));
+        }
+
+        self.open = false;
+    }
+
+    pub fn blank(&mut self) {
+        self.line(None, "");
+    }
+
+    // Starts a line that `push_str` keeps writing to until the next line
+    pub fn open(&mut self, row: impl Into<Option<usize>>) {
+        self.lines.push((row.into(), String::new()));
+        self.open = true;
+    }
+
+    pub fn push_str(&mut self, text: &str) {
+        if !self.open {
+            self.open(None);
+        }
+
+        if let Some((_, line)) = self.lines.last_mut() {
+            line.push_str(text);
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    pub fn insert(&mut self, index: usize, other: SourceLines) {
+        self.lines.splice(index..index, other.lines);
+    }
+
+    pub fn insert_blank(&mut self, index: usize) {
+        self.lines.insert(index, (None, String::new()));
+    }
+
+    // Appends lines extracted from a snippet that starts at `offset` in this
+    // source, such as a script element or front matter block
+    pub fn extend(&mut self, other: SourceLines, indent: &str, offset: usize) {
+        for (row, line) in other.lines {
+            let line = if line.is_empty() { line } else { format!("{}{}", indent, line) };
+            self.lines.push((row.map(|row| row + offset), line));
+        }
+
+        self.open = false;
+    }
+
+    pub fn trim_end(&mut self) {
+        while self.lines.last().is_some_and(|(_, line)| line.trim().is_empty()) {
+            self.lines.pop();
+        }
+
+        if let Some((_, line)) = self.lines.last_mut() {
+            line.truncate(line.trim_end().len());
+        }
+    }
+
+    pub fn rows(&self) -> Vec<Option<usize>> {
+        self.lines.iter().map(|(row, _)| *row).collect()
+    }
+
+    pub fn numbers(&self, total: usize) -> LineNumbers {
+        LineNumbers::new(self.lines.iter().map(|(row, _)| row.map(|row| row + 1)).collect(), total)
+    }
+
+    pub fn text(&self) -> String {
+        let mut text = String::new();
+
+        for (_, line) in &self.lines {
+            text.push_str(line);
+            text.push('\n');
+        }
+
+        text
+    }
+}
+
+// Line numbers kept apart from the content they belong to, one per line, so
+// formats can either prefix the text or show them beside it
+#[derive(Clone, Debug)]
+pub struct LineNumbers {
+    numbers: Vec<Option<usize>>,
+    total: usize,
+}
+
+impl LineNumbers {
+    pub fn new(numbers: Vec<Option<usize>>, total: usize) -> Self {
+        Self { numbers, total }
+    }
+
+    pub fn sequential(content: &str) -> Self {
+        let total = content.lines().count();
+        Self::new((1..=total).map(Some).collect(), total)
+    }
+
+    pub fn numbers(&self) -> &[Option<usize>] {
+        &self.numbers
+    }
+
+    pub fn apply(&self, content: &str) -> String {
+        let numbers = self.numbers.iter().copied().chain(std::iter::repeat(None));
+        number_with(numbers.zip(content.lines()), self.total)
+    }
+}
+
+fn number_with<'a>(lines: impl IntoIterator<Item = (Option<usize>, &'a str)>, total: usize) -> String {
+    let width = total.max(1).to_string().len();
+    let mut numbered = String::new();
+
+    for (number, line) in lines {
+        let line = line.trim_end_matches(['\r', '\n']);
+
+        let _ = match number {
+            Some(number) => writeln!(numbered, "{:>width$}  {}", number, line, width = width),
+            None if line.is_empty() => writeln!(numbered),
+            None => writeln!(numbered, "{:>width$}  {}", "", line, width = width),
+        };
+    }
+
+    numbered
+}
@@ -0,0 +1,74 @@
+use crate::app::state::search::ParsedQuery;
+use crate::model::options::Options;
+use crate::model::truncate::{TruncateMode, TruncateUnit};
+
+use super::gather::estimate_tokens;
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TruncateConfig {
+    pub excerpt: usize,
+    pub limit: Option<usize>,
+    pub mode: TruncateMode,
+    pub unit: TruncateUnit,
+}
+
+impl TruncateConfig {
+    pub fn new(options: &Options, query: Option<&ParsedQuery>) -> Self {
+        let mut config = Self {
+            excerpt: options.truncate_excerpt,
+            limit: options.truncate_limit,
+            mode: options.truncate_mode,
+            unit: options.truncate_unit,
+        };
+
+        // A maxlines: filter in the query overrides the configured cap
+        if let Some(max_lines) = query.and_then(|q| q.max_lines) {
+            config.limit = Some(max_lines);
+            config.unit = TruncateUnit::Lines;
+        }
+
+        // Keep a truncated file within a line cap smaller than both excerpts
+        if let (Some(limit), TruncateUnit::Lines) = (config.limit, config.unit) {
+            config.excerpt = config.excerpt.min(limit / 2);
+        }
+
+        config
+    }
+
+    pub fn exceeds(&self, content: &str) -> bool {
+        let Some(limit) = self.limit else {
+            return false;
+        };
+
+        let size = match self.unit {
+            TruncateUnit::Lines => content.lines().count(),
+            TruncateUnit::Tokens => estimate_tokens(content),
+            TruncateUnit::Bytes => content.len(),
+        };
+
+        size > limit
+    }
+}
+
+// Keeps the first and last `excerpt` lines and replaces the rest with a
+// marker line, which has no source line number of its own.
+pub fn excerpt(lines: Vec<(usize, String)>, excerpt: usize) -> Vec<(Option<usize>, String)> {
+    let excerpt = excerpt.max(1);
+
+    if lines.len() <= excerpt * 2 {
+        return lines.into_iter().map(|(number, line)| (Some(number), line)).collect();
+    }
+
+    let omitted = lines.len() - excerpt * 2;
+    let mut result = Vec::with_capacity(excerpt * 2 + 1);
+    let mut lines = lines.into_iter();
+
+    for (number, line) in lines.by_ref().take(excerpt) {
+        result.push((Some(number), line));
+    }
+
+    result.push((None, format!("… {} lines omitted …\n", omitted)));
+    result.extend(lines.skip(omitted).map(|(number, line)| (Some(number), line)));
+
+    result
+}
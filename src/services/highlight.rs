@@ -0,0 +1,107 @@
+use std::fmt::Write;
+
+use tree_sitter::Node;
+
+use crate::services::skeleton::generator::parse;
+use crate::services::skeleton::Language;
+
+// Renders content as escaped HTML with <span class="..."> around the tokens
+// tree-sitter recognises. Unparseable content is escaped as is.
+pub fn highlight(content: &str, language: Option<Language>) -> String {
+    let Some(tree) = language.and_then(|language| parse(content, language)) else {
+        return escape_html(content);
+    };
+
+    let mut spans = Vec::new();
+    collect_spans(tree.root_node(), &mut spans);
+
+    let mut output = String::with_capacity(content.len() * 2);
+    let mut offset = 0;
+
+    for (start, end, class) in spans {
+        if start < offset || end > content.len() {
+            continue;
+        }
+
+        output.push_str(&escape_html(&content[offset..start]));
+        let _ = write!(output, "<span class=\"{}\">{}</span>", class, escape_html(&content[start..end]));
+
+        offset = end;
+    }
+
+    output.push_str(&escape_html(&content[offset..]));
+    output
+}
+
+pub fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn collect_spans(node: Node, spans: &mut Vec<(usize, usize, &'static str)>) {
+    if let Some(class) = classify(node) {
+        spans.push((node.start_byte(), node.end_byte(), class));
+        return;
+    }
+
+    let mut cursor = node.walk();
+
+    for child in node.children(&mut cursor) {
+        collect_spans(child, spans);
+    }
+}
+
+fn classify(node: Node) -> Option<&'static str> {
+    let kind = node.kind();
+
+    if kind.contains("comment") {
+        return Some("comment");
+    }
+
+    if kind.contains("string") || matches!(kind, "char_literal" | "character" | "heredoc_body") {
+        return Some("string");
+    }
+
+    if kind.contains("number") || kind.contains("integer") || kind.contains("float") {
+        return Some("number");
+    }
+
+    if matches!(kind, "true" | "false" | "boolean" | "null" | "nil" | "none" | "self") {
+        return Some("constant");
+    }
+
+    if kind.ends_with("type_identifier") || matches!(kind, "primitive_type" | "predefined_type" | "builtin_type" | "tag_name") {
+        return Some("type");
+    }
+
+    // Anonymous word tokens are the grammar's keywords, e.g. "fn" or "return"
+    if !node.is_named() && kind.len() > 1 && kind.chars().all(|c| c.is_ascii_alphabetic() || c == '_') {
+        return Some("keyword");
+    }
+
+    if kind.ends_with("identifier") && is_function_name(node) {
+        return Some("function");
+    }
+
+    if matches!(kind, "attribute_name" | "property_identifier" | "field_identifier") {
+        return Some("property");
+    }
+
+    None
+}
+
+fn is_function_name(node: Node) -> bool {
+    let Some(parent) = node.parent() else {
+        return false;
+    };
+
+    let kind = parent.kind();
+
+    if kind.contains("function") || kind.contains("method") {
+        return parent.child_by_field_name("name") == Some(node);
+    }
+
+    kind.contains("call") && parent.child_by_field_name("function") == Some(node)
+}
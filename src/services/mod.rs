@@ -1,4 +1,7 @@
+pub mod dependency;
 pub mod filesystem;
+pub mod highlight;
 pub mod skeleton;
+pub mod symbol;
 pub mod tree;
 pub mod worker;
@@ -0,0 +1,489 @@
+use std::collections::HashMap;
+
+use tree_sitter::Node;
+
+use crate::services::filesystem::numbering::SourceLines;
+
+use super::language::Language;
+
+pub fn extract_document(output: &mut SourceLines, root: Node, source: &[u8], language: Language) {
+    match language {
+        Language::Json => extract_json(output, root, source),
+        Language::Toml => extract_toml(output, root, source),
+        Language::Yaml => extract_yaml(output, root, source),
+        _ => {}
+    }
+}
+
+fn extract_json(output: &mut SourceLines, root: Node, source: &[u8]) {
+    for child in named_children(root) {
+        if child.kind() == "comment" {
+            continue;
+        }
+
+        output.open(child.start_position().row);
+        write_json_value(output, child, source, 0);
+    }
+}
+
+fn write_json_value(output: &mut SourceLines, node: Node, source: &[u8], depth: usize) {
+    match node.kind() {
+        "object" => write_json_object(output, node, source, depth),
+        "array" => write_json_array(output, node, source, depth),
+        "string" => output.push_str("<string>"),
+        "number" => output.push_str("<number>"),
+        "true" | "false" => output.push_str("<boolean>"),
+        "null" => output.push_str("<null>"),
+        _ => output.push_str(node_text(node, source).trim()),
+    }
+}
+
+fn write_json_object(output: &mut SourceLines, node: Node, source: &[u8], depth: usize) {
+    let pairs: Vec<Node> = named_children(node)
+        .into_iter()
+        .filter(|child| child.kind() == "pair")
+        .collect();
+
+    if pairs.is_empty() {
+        output.push_str("{}");
+        return;
+    }
+
+    let indent = "    ".repeat(depth + 1);
+    output.push_str("{");
+
+    for (index, pair) in pairs.iter().enumerate() {
+        let key = pair
+            .child_by_field_name("key")
+            .map(|key| node_text(key, source))
+            .unwrap_or_default();
+
+        output.open(pair.start_position().row);
+        output.push_str(&format!("{}{}: ", indent, key));
+
+        match pair.child_by_field_name("value") {
+            Some(value) => write_json_value(output, value, source, depth + 1),
+            None => output.push_str("<null>"),
+        }
+
+        if index + 1 < pairs.len() {
+            output.push_str(",");
+        }
+    }
+
+    output.open(node.end_position().row);
+    output.push_str(&format!("{}}}", "    ".repeat(depth)));
+}
+
+fn write_json_array(output: &mut SourceLines, node: Node, source: &[u8], depth: usize) {
+    let items: Vec<Node> = named_children(node)
+        .into_iter()
+        .filter(|child| child.kind() != "comment")
+        .collect();
+
+    let Some(first) = items.first() else {
+        output.push_str("[]");
+        return;
+    };
+
+    let remaining = items.len() - 1;
+
+    if !matches!(first.kind(), "object" | "array") {
+        output.push_str("[");
+        write_json_value(output, *first, source, depth);
+
+        if remaining > 0 {
+            output.push_str(&format!(", ... {} more", remaining));
+        }
+
+        output.push_str("]");
+        return;
+    }
+
+    let indent = "    ".repeat(depth + 1);
+
+    output.push_str("[");
+    output.open(first.start_position().row);
+    output.push_str(&indent);
+    write_json_value(output, *first, source, depth + 1);
+
+    if remaining > 0 {
+        output.push_str(",");
+        output.open(None);
+        output.push_str(&format!("{}... {} more", indent, remaining));
+    }
+
+    output.open(node.end_position().row);
+    output.push_str(&format!("{}]", "    ".repeat(depth)));
+}
+
+// Block lines keep the source row they describe
+enum YamlValue {
+    Block(String, Vec<(Option<usize>, String)>),
+    Inline(String),
+}
+
+fn extract_yaml(output: &mut SourceLines, root: Node, source: &[u8]) {
+    let documents: Vec<Node> = named_children(root)
+        .into_iter()
+        .filter(|child| child.kind() == "document")
+        .collect();
+
+    for (index, document) in documents.iter().enumerate() {
+        if index > 0 {
+            output.line(None, "---");
+        }
+
+        for child in named_children(*document) {
+            if !matches!(child.kind(), "block_node" | "flow_node") {
+                continue;
+            }
+
+            let row = child.start_position().row;
+
+            match yaml_value(child, source) {
+                YamlValue::Inline(text) => output.line(row, text),
+                YamlValue::Block(prefix, lines) => {
+                    if !prefix.is_empty() {
+                        output.line(row, prefix);
+                    }
+
+                    for (row, line) in lines {
+                        output.line(row, line);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn yaml_value(node: Node, source: &[u8]) -> YamlValue {
+    let mut prefix: Vec<String> = Vec::new();
+    let mut value: Option<YamlValue> = None;
+
+    for child in named_children(node) {
+        match child.kind() {
+            "anchor" | "tag" => prefix.push(node_text(child, source)),
+            "alias" => value = Some(YamlValue::Inline(node_text(child, source))),
+            "block_mapping" => value = Some(YamlValue::Block(String::new(), yaml_mapping(child, source))),
+            "block_sequence" => value = Some(YamlValue::Block(String::new(), yaml_sequence(child, source))),
+            "flow_mapping" => value = Some(YamlValue::Inline(yaml_flow_mapping(child, source))),
+            "flow_sequence" => value = Some(YamlValue::Inline(yaml_flow_sequence(child, source))),
+            "plain_scalar" => value = Some(YamlValue::Inline(yaml_scalar_hint(child).to_string())),
+            "block_scalar" | "double_quote_scalar" | "single_quote_scalar" => {
+                value = Some(YamlValue::Inline("<string>".to_string()));
+            }
+            _ => {}
+        }
+    }
+
+    let prefix = prefix.join(" ");
+
+    match value {
+        Some(YamlValue::Block(_, lines)) => YamlValue::Block(prefix, lines),
+        Some(YamlValue::Inline(text)) if prefix.is_empty() => YamlValue::Inline(text),
+        Some(YamlValue::Inline(text)) => YamlValue::Inline(format!("{} {}", prefix, text)),
+        None if prefix.is_empty() => YamlValue::Inline("<null>".to_string()),
+        None => YamlValue::Inline(prefix),
+    }
+}
+
+fn yaml_mapping(node: Node, source: &[u8]) -> Vec<(Option<usize>, String)> {
+    let mut lines = Vec::new();
+
+    for pair in named_children(node) {
+        if pair.kind() != "block_mapping_pair" {
+            continue;
+        }
+
+        let key = pair
+            .child_by_field_name("key")
+            .map(|key| node_text(key, source))
+            .unwrap_or_default();
+
+        let value = pair
+            .child_by_field_name("value")
+            .map(|value| yaml_value(value, source))
+            .unwrap_or_else(|| YamlValue::Inline("<null>".to_string()));
+
+        let row = Some(pair.start_position().row);
+
+        match value {
+            YamlValue::Inline(text) => lines.push((row, format!("{}: {}", key, text))),
+            YamlValue::Block(prefix, children) => {
+                if prefix.is_empty() {
+                    lines.push((row, format!("{}:", key)));
+                } else {
+                    lines.push((row, format!("{}: {}", key, prefix)));
+                }
+
+                lines.extend(children.into_iter().map(|(row, line)| (row, format!("  {}", line))));
+            }
+        }
+    }
+
+    lines
+}
+
+fn yaml_sequence(node: Node, source: &[u8]) -> Vec<(Option<usize>, String)> {
+    let items: Vec<Node> = named_children(node)
+        .into_iter()
+        .filter(|child| child.kind() == "block_sequence_item")
+        .collect();
+
+    let mut lines = Vec::new();
+
+    let Some(first) = items.first() else {
+        return lines;
+    };
+
+    let value = named_children(*first)
+        .into_iter()
+        .find(|child| matches!(child.kind(), "block_node" | "flow_node"))
+        .map(|child| yaml_value(child, source))
+        .unwrap_or_else(|| YamlValue::Inline("<null>".to_string()));
+
+    let row = Some(first.start_position().row);
+
+    match value {
+        YamlValue::Inline(text) => lines.push((row, format!("- {}", text))),
+        YamlValue::Block(prefix, children) if !prefix.is_empty() => {
+            lines.push((row, format!("- {}", prefix)));
+            lines.extend(children.into_iter().map(|(row, line)| (row, format!("  {}", line))));
+        }
+        YamlValue::Block(_, children) => {
+            for (index, (row, line)) in children.into_iter().enumerate() {
+                if index == 0 {
+                    lines.push((row, format!("- {}", line)));
+                } else {
+                    lines.push((row, format!("  {}", line)));
+                }
+            }
+        }
+    }
+
+    if items.len() > 1 {
+        lines.push((None, format!("# ... {} more", items.len() - 1)));
+    }
+
+    lines
+}
+
+fn yaml_flow_mapping(node: Node, source: &[u8]) -> String {
+    let mut entries = Vec::new();
+
+    for child in named_children(node) {
+        match child.kind() {
+            "flow_pair" => {
+                let key = child
+                    .child_by_field_name("key")
+                    .map(|key| node_text(key, source))
+                    .unwrap_or_default();
+
+                let value = child
+                    .child_by_field_name("value")
+                    .map(|value| yaml_inline(value, source))
+                    .unwrap_or_else(|| "<null>".to_string());
+
+                entries.push(format!("{}: {}", key, value));
+            }
+            "flow_node" => entries.push(node_text(child, source)),
+            _ => {}
+        }
+    }
+
+    if entries.is_empty() {
+        return "{}".to_string();
+    }
+
+    format!("{{ {} }}", entries.join(", "))
+}
+
+fn yaml_flow_sequence(node: Node, source: &[u8]) -> String {
+    let items: Vec<Node> = named_children(node)
+        .into_iter()
+        .filter(|child| matches!(child.kind(), "flow_node" | "flow_pair"))
+        .collect();
+
+    let Some(first) = items.first() else {
+        return "[]".to_string();
+    };
+
+    let first = if first.kind() == "flow_pair" {
+        yaml_flow_mapping(node, source)
+    } else {
+        yaml_inline(*first, source)
+    };
+
+    if items.len() > 1 {
+        format!("[{}, ... {} more]", first, items.len() - 1)
+    } else {
+        format!("[{}]", first)
+    }
+}
+
+fn yaml_inline(node: Node, source: &[u8]) -> String {
+    match yaml_value(node, source) {
+        YamlValue::Inline(text) => text,
+        YamlValue::Block(prefix, _) if !prefix.is_empty() => prefix,
+        YamlValue::Block(_, _) => "...".to_string(),
+    }
+}
+
+fn yaml_scalar_hint(node: Node) -> &'static str {
+    let kind = node
+        .named_child(0)
+        .map(|child| child.kind())
+        .unwrap_or("string_scalar");
+
+    match kind {
+        "boolean_scalar" => "<boolean>",
+        "float_scalar" => "<float>",
+        "integer_scalar" => "<integer>",
+        "null_scalar" => "<null>",
+        "timestamp_scalar" => "<timestamp>",
+        _ => "<string>",
+    }
+}
+
+fn extract_toml(output: &mut SourceLines, root: Node, source: &[u8]) {
+    let children = named_children(root);
+    let mut counts: HashMap<String, usize> = HashMap::new();
+
+    for child in &children {
+        if child.kind() == "table_array_element" {
+            *counts.entry(toml_header(*child, source)).or_insert(0) += 1;
+        }
+    }
+
+    let mut seen: Vec<String> = Vec::new();
+    let mut skipping: Option<String> = None;
+
+    for child in children {
+        match child.kind() {
+            "pair" => output.line(child.start_position().row, toml_pair(child, source)),
+            "table" | "table_array_element" => {
+                let header = toml_header(child, source);
+
+                if let Some(parent) = &skipping {
+                    if header.starts_with(&format!("{}.", parent)) {
+                        continue;
+                    }
+
+                    skipping = None;
+                }
+
+                let is_array = child.kind() == "table_array_element";
+
+                if is_array && seen.contains(&header) {
+                    skipping = Some(header);
+                    continue;
+                }
+
+                if !output.is_empty() {
+                    output.blank();
+                }
+
+                let row = child.start_position().row;
+
+                if is_array {
+                    output.line(row, format!("[[{}]]", header));
+                } else {
+                    output.line(row, format!("[{}]", header));
+                }
+
+                for pair in named_children(child) {
+                    if pair.kind() == "pair" {
+                        output.line(pair.start_position().row, toml_pair(pair, source));
+                    }
+                }
+
+                if is_array {
+                    let count = counts.get(&header).copied().unwrap_or(1);
+
+                    if count > 1 {
+                        output.line(None, format!("# ... {} more [[{}]]", count - 1, header));
+                    }
+
+                    seen.push(header);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn toml_header(node: Node, source: &[u8]) -> String {
+    named_children(node)
+        .into_iter()
+        .find(|child| matches!(child.kind(), "bare_key" | "dotted_key" | "quoted_key"))
+        .map(|child| node_text(child, source))
+        .unwrap_or_default()
+}
+
+fn toml_pair(node: Node, source: &[u8]) -> String {
+    let children = named_children(node);
+
+    let key = children
+        .first()
+        .map(|child| node_text(*child, source))
+        .unwrap_or_default();
+
+    let value = children
+        .iter()
+        .skip(1)
+        .find(|child| child.kind() != "comment")
+        .map(|child| toml_value(*child, source))
+        .unwrap_or_default();
+
+    format!("{} = {}", key, value)
+}
+
+fn toml_value(node: Node, source: &[u8]) -> String {
+    match node.kind() {
+        "array" => {
+            let items: Vec<Node> = named_children(node)
+                .into_iter()
+                .filter(|child| child.kind() != "comment")
+                .collect();
+
+            match items.first() {
+                None => "[]".to_string(),
+                Some(first) if items.len() > 1 => {
+                    format!("[{}, ... {} more]", toml_value(*first, source), items.len() - 1)
+                }
+                Some(first) => format!("[{}]", toml_value(*first, source)),
+            }
+        }
+        "inline_table" => {
+            let pairs: Vec<String> = named_children(node)
+                .into_iter()
+                .filter(|child| child.kind() == "pair")
+                .map(|pair| toml_pair(pair, source))
+                .collect();
+
+            if pairs.is_empty() {
+                "{}".to_string()
+            } else {
+                format!("{{ {} }}", pairs.join(", "))
+            }
+        }
+        "boolean" => "<boolean>".to_string(),
+        "float" => "<float>".to_string(),
+        "integer" => "<integer>".to_string(),
+        "local_date" => "<date>".to_string(),
+        "local_date_time" | "offset_date_time" => "<datetime>".to_string(),
+        "local_time" => "<time>".to_string(),
+        "string" => "<string>".to_string(),
+        _ => node_text(node, source),
+    }
+}
+
+fn named_children(node: Node) -> Vec<Node> {
+    let mut cursor = node.walk();
+    node.named_children(&mut cursor).collect()
+}
+
+fn node_text(node: Node, source: &[u8]) -> String {
+    String::from_utf8_lossy(&source[node.start_byte()..node.end_byte()]).to_string()
+}
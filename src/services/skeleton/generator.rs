@@ -1,16 +1,34 @@
-use std::fmt::Write;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
-use std::path::Path;
-use std::sync::Arc;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
 
 use ignore::WalkBuilder;
-use tree_sitter::{Node, Parser};
+use tree_sitter::{Language as TsLanguage, Node, Parser, Tree};
 
+use crate::app::state::search::{Command, ParsedQuery};
 use crate::model::error::SwarmResult;
 use crate::model::options::Options;
+use crate::model::output::{OutputContext, OutputFile};
+use crate::model::prompt::apply_prompt;
+use crate::model::skeleton::SkeletonLevel;
 use crate::services::filesystem::filter::{GlobPathFilter, PathFilter};
+use crate::services::filesystem::gather::matches_query;
+use crate::services::filesystem::git::GitService;
+use crate::services::filesystem::numbering::SourceLines;
 
+use super::data;
+use super::html;
 use super::language::Language;
+use super::markdown;
+use super::query::{has_custom_query, NodeCategory, Rules};
+
+thread_local! {
+    static PARSERS: RefCell<HashMap<&'static str, Parser>> = RefCell::new(HashMap::new());
+}
 
 #[derive(Clone, Debug)]
 pub struct SkeletonStats {
@@ -19,6 +37,33 @@ pub struct SkeletonStats {
     pub token_count: usize,
 }
 
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SkeletonConfig {
+    pub doc_comments: bool,
+    pub doc_summary: bool,
+    pub expand_lines: usize,
+    pub level: SkeletonLevel,
+    pub line_numbers: bool,
+    pub public_only: bool,
+}
+
+impl SkeletonConfig {
+    pub fn from_options(options: &Options) -> Self {
+        Self {
+            doc_comments: options.skeleton_doc_comments,
+            doc_summary: options.skeleton_doc_summary,
+            expand_lines: options.skeleton_expand_lines,
+            level: options.skeleton_level,
+            line_numbers: options.line_numbers,
+            public_only: options.skeleton_public_only,
+        }
+    }
+
+    fn filters_private(&self) -> bool {
+        self.public_only || self.level == SkeletonLevel::Signatures
+    }
+}
+
 #[derive(Clone)]
 pub struct SkeletonGenerator;
 
@@ -38,6 +83,91 @@ impl SkeletonGenerator {
         paths: &[String],
         options: &Options,
     ) -> SwarmResult<(String, SkeletonStats)> {
+        self.generate_with_context(paths, options, None, None)
+    }
+
+    pub fn generate_with_context(
+        &self,
+        paths: &[String],
+        options: &Options,
+        git_service: Option<&GitService>,
+        query: Option<&ParsedQuery>,
+    ) -> SwarmResult<(String, SkeletonStats)> {
+        self.generate_with_progress(paths, options, git_service, query, &mut |_, _| {})
+    }
+
+    pub fn generate_with_progress(
+        &self,
+        paths: &[String],
+        options: &Options,
+        git_service: Option<&GitService>,
+        query: Option<&ParsedQuery>,
+        progress: &mut dyn FnMut(usize, usize),
+    ) -> SwarmResult<(String, SkeletonStats)> {
+        let files = self.collect_with_progress(paths, options, git_service, query, progress)?;
+
+        let output_format = query
+            .and_then(|q| q.format_override)
+            .unwrap_or(options.output_format);
+
+        let context = OutputContext::new(output_format, options, git_service)?;
+        let output = output_format.format_with(&files, &context)?;
+        let output = apply_prompt(output, options, paths, git_service, files.len())?;
+
+        let stats = SkeletonStats {
+            file_count: files.len(),
+            line_count: output.lines().count(),
+            token_count: estimate_skeleton_tokens(&output),
+        };
+
+        Ok((output, stats))
+    }
+
+    pub fn collect(&self, paths: &[String], options: &Options) -> SwarmResult<Vec<OutputFile>> {
+        self.collect_with_context(paths, options, None, None)
+    }
+
+    pub fn collect_with_context(
+        &self,
+        paths: &[String],
+        options: &Options,
+        git_service: Option<&GitService>,
+        query: Option<&ParsedQuery>,
+    ) -> SwarmResult<Vec<OutputFile>> {
+        self.collect_with_progress(paths, options, git_service, query, &mut |_, _| {})
+    }
+
+    fn collect_with_progress(
+        &self,
+        paths: &[String],
+        options: &Options,
+        git_service: Option<&GitService>,
+        query: Option<&ParsedQuery>,
+        progress: &mut dyn FnMut(usize, usize),
+    ) -> SwarmResult<Vec<OutputFile>> {
+        let mut config = SkeletonConfig::from_options(options);
+        config.line_numbers |= query.is_some_and(|q| q.has_command(Command::LineNumbers));
+
+        let files = self.resolve_files(paths, options, git_service, query)?;
+        let mut skeletons = Vec::new();
+
+        process_parallel(
+            &files,
+            |path| self.process_file(path, &config),
+            progress,
+            &mut |skeleton| skeletons.push(skeleton),
+        );
+
+        Ok(skeletons)
+    }
+
+    pub(crate) fn resolve_files(
+        &self,
+        paths: &[String],
+        options: &Options,
+        git_service: Option<&GitService>,
+        query: Option<&ParsedQuery>,
+    ) -> SwarmResult<Vec<PathBuf>> {
         let filter: Arc<dyn PathFilter> = Arc::new(GlobPathFilter::from_options(options)?);
         let mut files = Vec::new();
 
@@ -47,52 +177,35 @@ impl SkeletonGenerator {
             if path.is_dir() {
                 self.collect_directory(path, &mut files, &filter)?;
             } else if path.is_file() {
-                if let Some(entry) = self.process_file(path) {
-                    files.push(entry);
-                }
+                files.push(path.to_path_buf());
             }
         }
 
-        files.sort_by(|a, b| a.0.cmp(&b.0));
-
-        let mut output = String::new();
+        files.retain(|path| {
+            Language::from_path(path).is_some_and(|language| language.has_skeleton_support())
+                && query.is_none_or(|q| matches_query(path, q, git_service))
+        });
 
-        for (path, skeleton) in &files {
-            let _ = writeln!(output, "[{}]", path);
-            output.push_str(skeleton);
-            output.push('\n');
-        }
-
-        let stats = SkeletonStats {
-            file_count: files.len(),
-            line_count: output.lines().count(),
-            token_count: estimate_skeleton_tokens(&output),
-        };
+        files.sort_by_cached_key(|path| path.display().to_string());
 
-        Ok((output, stats))
+        Ok(files)
     }
 
-    fn process_file(&self, path: &Path) -> Option<(String, String)> {
+    fn process_file(&self, path: &Path, config: &SkeletonConfig) -> Option<OutputFile> {
         let language = Language::from_path(path)?;
 
-        if !language.has_skeleton_support() {
-            return None;
-        }
-
         let content = fs::read_to_string(path).ok()?;
-        let skeleton = extract_skeleton(&content, language)?;
+        let skeleton = extract_skeleton(&content, language, config)?;
 
-        if skeleton.trim().is_empty() {
-            return None;
-        }
+        let numbers = config.line_numbers.then(|| skeleton.numbers(content.lines().count()));
 
-        Some((path.display().to_string(), skeleton))
+        Some(OutputFile::new(path.display().to_string(), skeleton.text()).with_numbers(numbers))
     }
 
     fn collect_directory(
         &self,
         path: &Path,
-        files: &mut Vec<(String, String)>,
+        files: &mut Vec<PathBuf>,
         filter: &Arc<dyn PathFilter>,
     ) -> SwarmResult<()> {
         let walker = WalkBuilder::new(path)
@@ -113,119 +226,232 @@ impl SkeletonGenerator {
                 continue;
             }
 
-            if let Some(entry) = self.process_file(entry_path) {
-                files.push(entry);
-            }
+            files.push(entry_path.to_path_buf());
         }
 
         Ok(())
     }
 }
 
-#[derive(Clone, Copy, Eq, PartialEq)]
-enum NodeCategory {
-    Class,
-    Constant,
-    Definition,
-    Import,
-    Wrapper,
-}
+// Runs `work` over the files on a thread pool and hands each result to `emit`
+// in path order, as soon as every file before it has finished
+pub(crate) fn process_parallel<T, F>(
+    files: &[PathBuf],
+    work: F,
+    progress: &mut dyn FnMut(usize, usize),
+    emit: &mut dyn FnMut(T),
+) where
+    T: Send,
+    F: Fn(&Path) -> Option<T> + Sync,
+{
+    let total = files.len();
+
+    let threads = thread::available_parallelism()
+        .map(|count| count.get())
+        .unwrap_or(1)
+        .clamp(1, total.max(1));
+
+    let next = AtomicUsize::new(0);
+    let mut pending: BTreeMap<usize, Option<T>> = BTreeMap::new();
+    let mut emitted = 0;
+
+    thread::scope(|scope| {
+        let (sender, receiver) = mpsc::channel();
+
+        for _ in 0..threads {
+            let sender = sender.clone();
+            let next = &next;
+            let work = &work;
+
+            scope.spawn(move || {
+                loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+
+                    if index >= total {
+                        break;
+                    }
 
-fn classify_node(kind: &str, language: Language) -> Option<NodeCategory> {
-    if language.import_types().contains(&kind) {
-        Some(NodeCategory::Import)
-    } else if language.wrapper_types().contains(&kind) {
-        Some(NodeCategory::Wrapper)
-    } else if language.class_types().contains(&kind) {
-        Some(NodeCategory::Class)
-    } else if language.definition_types().contains(&kind) {
-        Some(NodeCategory::Definition)
-    } else if language.constant_types().contains(&kind) {
-        Some(NodeCategory::Constant)
-    } else {
-        None
-    }
-}
+                    let entry = work(&files[index]);
 
-fn find_body<'a>(node: Node<'a>, language: Language) -> Option<Node<'a>> {
-    if let Some(body) = node.child_by_field_name(language.body_field()) {
-        return Some(body);
-    }
+                    if sender.send((index, entry)).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
 
-    let kinds = language.body_kinds();
+        drop(sender);
 
-    if kinds.is_empty() {
-        return None;
-    }
+        for (processed, (index, entry)) in receiver.iter().enumerate() {
+            pending.insert(index, entry);
 
-    let mut cursor = node.walk();
+            while let Some(entry) = pending.remove(&emitted) {
+                emitted += 1;
 
-    for child in node.children(&mut cursor) {
-        if kinds.contains(&child.kind()) {
-            return Some(child);
-        }
-    }
+                if let Some(entry) = entry {
+                    emit(entry);
+                }
+            }
 
-    None
-}
+            let processed = processed + 1;
 
-fn extract_skeleton(content: &str, language: Language) -> Option<String> {
-    let mut parser = Parser::new();
-    parser.set_language(&language.grammar()).ok()?;
+            if processed.is_multiple_of(100) || processed == total {
+                progress(processed, total);
+            }
+        }
+    });
+}
 
-    let tree = parser.parse(content, None)?;
+pub(crate) fn extract_skeleton(
+    content: &str,
+    language: Language,
+    config: &SkeletonConfig,
+) -> Option<SourceLines> {
+    let tree = parse(content, language)?;
     let root = tree.root_node();
     let source = content.as_bytes();
 
-    let mut output = String::new();
-    extract_top_level(&mut output, root, source, language, 0);
+    let mut output = SourceLines::new();
+
+    let custom = has_custom_query(language);
+
+    match language {
+        Language::Html if !custom => html::extract_document(&mut output, root, source, config, 0),
+        Language::Json | Language::Toml | Language::Yaml if !custom => {
+            data::extract_document(&mut output, root, source, language);
+        }
+        Language::Markdown if !custom => markdown::extract_document(&mut output, root, source, config),
+        _ => {
+            let rules = Rules::collect(root, source, language)?;
+            extract_top_level(&mut output, root, source, language, &rules, config, 0);
+        }
+    }
 
-    let trimmed = output.trim_end().to_string();
+    output.trim_end();
 
-    if trimmed.is_empty() {
+    if output.is_empty() {
         return None;
     }
 
-    Some(format!("{}\n", trimmed))
+    Some(output)
+}
+
+pub(crate) fn parse(content: &str, language: Language) -> Option<Tree> {
+    parse_grammar(content, language.id(), || language.grammar())
+}
+
+// Markdown inline content has its own grammar outside the Language enum
+pub(super) fn parse_markdown_inline(content: &str) -> Option<Tree> {
+    parse_grammar(content, "markdown_inline", || tree_sitter_md::INLINE_LANGUAGE.into())
+}
+
+fn parse_grammar(content: &str, id: &'static str, grammar: impl FnOnce() -> TsLanguage) -> Option<Tree> {
+    let cached = PARSERS.with(|parsers| parsers.borrow_mut().remove(id));
+
+    let mut parser = match cached {
+        Some(parser) => parser,
+        None => {
+            let mut parser = Parser::new();
+            parser.set_language(&grammar()).ok()?;
+            parser
+        }
+    };
+
+    let tree = parser.parse(content, None);
+
+    PARSERS.with(|parsers| parsers.borrow_mut().insert(id, parser));
+
+    tree
 }
 
 fn extract_top_level(
-    output: &mut String,
+    output: &mut SourceLines,
     node: Node,
     source: &[u8],
     language: Language,
+    rules: &Rules,
+    config: &SkeletonConfig,
     depth: usize,
 ) {
     let mut cursor = node.walk();
     let children: Vec<Node> = node.children(&mut cursor).collect();
     let mut prev_category: Option<NodeCategory> = None;
+    let mut docs: Vec<Node> = Vec::new();
+    let mut inner_docs: Vec<Node> = Vec::new();
+    let mut after_inner_docs = false;
 
     for child in children {
         let kind = child.kind();
 
-        let category = match classify_node(kind, language) {
+        if config.doc_comments && is_doc_comment(child, source, language) {
+            if is_inner_doc_comment(child, source) {
+                inner_docs.push(child);
+            } else {
+                docs.push(child);
+            }
+
+            continue;
+        }
+
+        if !inner_docs.is_empty() {
+            append_doc_comments(output, &inner_docs, source, config, depth);
+            inner_docs.clear();
+            after_inner_docs = true;
+        }
+
+        let category = match rules.category(child) {
             Some(c) => c,
-            None => continue,
+            None => {
+                if kind != "attribute_item" {
+                    docs.clear();
+                }
+
+                continue;
+            }
         };
 
+        let is_hidden = (config.level == SkeletonLevel::Signatures && category == NodeCategory::Import)
+            || (config.filters_private() && !is_public(child, source, language));
+
+        if is_hidden {
+            docs.clear();
+            continue;
+        }
+
         let before_len = output.len();
 
         match category {
-            NodeCategory::Wrapper => extract_wrapper(output, child, source, language, depth),
-            NodeCategory::Class => extract_class(output, child, source, language, depth),
-            NodeCategory::Definition => extract_definition(output, child, source, language, depth),
+            NodeCategory::Wrapper => extract_wrapper(output, child, source, language, rules, config, depth),
+            NodeCategory::Class => extract_class(output, child, source, language, rules, config, depth),
+            NodeCategory::Definition => extract_definition(output, child, source, language, rules, config, depth),
             NodeCategory::Import => append_node_text(output, child, source, depth),
-            NodeCategory::Constant => extract_constant(output, child, source, language, depth),
+            NodeCategory::Constant => {
+                if config.doc_comments && is_docstring(child) {
+                    append_doc_comments(output, &[child], source, config, depth);
+                } else {
+                    extract_constant(output, child, source, language, rules, config, depth);
+                }
+            }
         }
 
         let wrote_something = output.len() > before_len;
 
+        if wrote_something && !docs.is_empty() {
+            let mut doc_text = SourceLines::new();
+            append_doc_comments(&mut doc_text, &docs, source, config, depth);
+            output.insert(before_len, doc_text);
+        }
+
+        docs.clear();
+
         if wrote_something {
-            if let Some(prev) = prev_category {
+            if after_inner_docs {
+                output.insert_blank(before_len);
+                after_inner_docs = false;
+            } else if let Some(prev) = prev_category {
                 let is_multiline = child.start_position().row != child.end_position().row;
 
-                let output_lines = output[before_len..].lines().count();
-                let was_single_line = output_lines <= 1;
+                let was_single_line = output.len() - before_len <= 1;
 
                 let needs_blank = if depth == 0 {
                     if was_single_line {
@@ -240,74 +466,108 @@ fn extract_top_level(
                 };
 
                 if needs_blank {
-                    output.insert(before_len, '\n');
+                    output.insert_blank(before_len);
                 }
             }
 
             prev_category = Some(category);
         }
     }
+
+    if !inner_docs.is_empty() {
+        append_doc_comments(output, &inner_docs, source, config, depth);
+    }
 }
 
 fn extract_definition(
-    output: &mut String,
+    output: &mut SourceLines,
     node: Node,
     source: &[u8],
     language: Language,
+    rules: &Rules,
+    config: &SkeletonConfig,
     depth: usize,
 ) {
     let indent = "    ".repeat(depth);
 
-    if let Some(body) = find_body(node, language) {
+    if config.level == SkeletonLevel::Expanded {
+        let line_count = node.end_position().row - node.start_position().row + 1;
+
+        if line_count <= config.expand_lines {
+            append_verbatim(output, node, source, depth);
+            return;
+        }
+    }
+
+    if let Some(body) = rules.body(node) {
         let sig_start = node.start_byte();
         let sig_end = body.start_byte();
         let sig_text = String::from_utf8_lossy(&source[sig_start..sig_end]);
         let sig = sig_text.trim_end();
 
-        let _ = writeln!(output, "{}{}{}", indent, sig, language.ellipsis());
+        let docstring = if config.doc_comments && language == Language::Python {
+            find_docstring(body)
+        } else {
+            None
+        };
+
+        let row = node.start_position().row;
+
+        if let Some(docstring) = docstring {
+            output.line(row, format!("{}{}", indent, sig));
+            append_doc_comments(output, &[docstring], source, config, depth + 1);
+            output.line(None, format!("{}    ...", indent));
+            return;
+        }
+
+        output.line(row, format!("{}{}{}", indent, sig, language.ellipsis()));
     } else {
         append_node_text(output, node, source, depth);
     }
 }
 
 fn extract_class(
-    output: &mut String,
+    output: &mut SourceLines,
     node: Node,
     source: &[u8],
     language: Language,
+    rules: &Rules,
+    config: &SkeletonConfig,
     depth: usize,
 ) {
     let indent = "    ".repeat(depth);
 
-    if let Some(body) = find_body(node, language) {
+    if let Some(body) = rules.body(node) {
         let sig_start = node.start_byte();
         let sig_end = body.start_byte();
         let sig_text = String::from_utf8_lossy(&source[sig_start..sig_end]);
         let sig = sig_text.trim_end();
+        let row = node.start_position().row;
+        let end_row = body.end_position().row;
 
         match language {
             Language::Python => {
-                let _ = writeln!(output, "{}{}", indent, sig);
-                extract_class_body(output, body, source, language, depth + 1);
+                output.line(row, format!("{}{}", indent, sig));
+                extract_class_body(output, body, source, language, rules, config, depth + 1);
             }
             Language::Rust => {
-                let _ = writeln!(output, "{}{} {{", indent, sig);
-                extract_class_body(output, body, source, language, depth + 1);
-                let _ = writeln!(output, "{}}}", indent);
+                output.line(row, format!("{}{} {{", indent, sig));
+                extract_class_body(output, body, source, language, rules, config, depth + 1);
+                output.line(end_row, format!("{}}}", indent));
             }
             Language::Css => {
-                if let Some(collapsed) = try_collapse_css_body(body, source, language) {
-                    let _ = writeln!(output, "{}{} {{ {} }}", indent, sig, collapsed);
+                if let Some(collapsed) = try_collapse_css_body(body, source, language, rules) {
+                    output.line(row, format!("{}{} {{ {} }}", indent, sig, collapsed));
                 } else {
-                    let _ = writeln!(output, "{}{} {{", indent, sig);
-                    extract_class_body(output, body, source, language, depth + 1);
-                    let _ = writeln!(output, "{}}}", indent);
+                    output.line(row, format!("{}{} {{", indent, sig));
+                    extract_class_body(output, body, source, language, rules, config, depth + 1);
+                    output.line(end_row, format!("{}}}", indent));
                 }
             }
             _ => {
-                let _ = writeln!(output, "{}{} {{", indent, sig);
-                extract_class_body(output, body, source, language, depth + 1);
-                let _ = writeln!(output, "{}}}", indent);
+                output.line(row, format!("{}{} {{", indent, sig));
+                extract_class_body(output, body, source, language, rules, config, depth + 1);
+                output.line(end_row, format!("{}}}", indent));
             }
         }
     } else {
@@ -315,16 +575,14 @@ fn extract_class(
     }
 }
 
-fn try_collapse_css_body(body: Node, source: &[u8], language: Language) -> Option<String> {
+fn try_collapse_css_body(body: Node, source: &[u8], language: Language, rules: &Rules) -> Option<String> {
     let mut cursor = body.walk();
     let children: Vec<Node> = body.children(&mut cursor).collect();
 
     let skeleton_children: Vec<Node> = children
         .into_iter()
         .filter(|child| {
-            let kind = child.kind();
-            language.definition_types().contains(&kind)
-                || language.class_types().contains(&kind)
+            rules.is(*child, NodeCategory::Definition) || rules.is(*child, NodeCategory::Class)
         })
         .collect();
 
@@ -333,10 +591,9 @@ fn try_collapse_css_body(body: Node, source: &[u8], language: Language) -> Optio
     }
 
     let child = skeleton_children[0];
-    let kind = child.kind();
 
-    if language.definition_types().contains(&kind) {
-        if let Some(child_body) = find_body(child, language) {
+    if rules.is(child, NodeCategory::Definition) {
+        if let Some(child_body) = rules.body(child) {
             let sig_start = child.start_byte();
             let sig_end = child_body.start_byte();
             let sig_text = String::from_utf8_lossy(&source[sig_start..sig_end]);
@@ -346,14 +603,14 @@ fn try_collapse_css_body(body: Node, source: &[u8], language: Language) -> Optio
         }
     }
 
-    if language.class_types().contains(&kind) {
-        if let Some(child_body) = find_body(child, language) {
+    if rules.is(child, NodeCategory::Class) {
+        if let Some(child_body) = rules.body(child) {
             let sig_start = child.start_byte();
             let sig_end = child_body.start_byte();
             let sig_text = String::from_utf8_lossy(&source[sig_start..sig_end]);
             let sig = sig_text.trim_end();
 
-            if let Some(collapsed) = try_collapse_css_body(child_body, source, language) {
+            if let Some(collapsed) = try_collapse_css_body(child_body, source, language, rules) {
                 return Some(format!("{} {{ {} }}", sig, collapsed));
             }
         }
@@ -363,46 +620,86 @@ fn try_collapse_css_body(body: Node, source: &[u8], language: Language) -> Optio
 }
 
 fn extract_class_body(
-    output: &mut String,
+    output: &mut SourceLines,
     body: Node,
     source: &[u8],
     language: Language,
+    rules: &Rules,
+    config: &SkeletonConfig,
     depth: usize,
 ) {
     let indent = "    ".repeat(depth);
     let mut cursor = body.walk();
     let children: Vec<Node> = body.children(&mut cursor).collect();
 
-    let has_skeleton_content = children.iter().any(|child| {
-        let kind = child.kind();
-        language.definition_types().contains(&kind)
-            || language.class_types().contains(&kind)
-            || language.wrapper_types().contains(&kind)
-    });
+    let docstring = if config.doc_comments && language == Language::Python {
+        find_docstring(body)
+    } else {
+        None
+    };
+
+    if let Some(docstring) = docstring {
+        append_doc_comments(output, &[docstring], source, config, depth);
+    }
+
+    let filter_private = config.filters_private() && !is_public_container(body);
+
+    let children: Vec<Node> = children
+        .into_iter()
+        .filter(|child| !filter_private || !is_member(*child, rules) || is_public(*child, source, language))
+        .collect();
+
+    let has_skeleton_content = children.iter().any(|child| is_member(*child, rules));
 
     if !has_skeleton_content {
-        let _ = writeln!(output, "{}...", indent);
+        output.line(None, format!("{}...", indent));
         return;
     }
 
+    let mut docs: Vec<Node> = Vec::new();
+
     for child in children {
-        let kind = child.kind();
+        if config.doc_comments && is_doc_comment(child, source, language) {
+            docs.push(child);
+            continue;
+        }
 
-        if language.definition_types().contains(&kind) {
-            extract_definition(output, child, source, language, depth);
-        } else if language.class_types().contains(&kind) {
-            extract_class(output, child, source, language, depth);
-        } else if language.wrapper_types().contains(&kind) {
-            extract_wrapper(output, child, source, language, depth);
+        let before_len = output.len();
+
+        if rules.is(child, NodeCategory::Definition) {
+            extract_definition(output, child, source, language, rules, config, depth);
+        } else if rules.is(child, NodeCategory::Class) {
+            extract_class(output, child, source, language, rules, config, depth);
+        } else if rules.is(child, NodeCategory::Wrapper) {
+            extract_wrapper(output, child, source, language, rules, config, depth);
+        } else if child.kind() == "attribute_item" {
+            continue;
+        }
+
+        if output.len() > before_len && !docs.is_empty() {
+            let mut doc_text = SourceLines::new();
+            append_doc_comments(&mut doc_text, &docs, source, config, depth);
+            output.insert(before_len, doc_text);
         }
+
+        docs.clear();
     }
 }
 
+fn is_member(node: Node, rules: &Rules) -> bool {
+    matches!(
+        rules.category(node),
+        Some(NodeCategory::Definition | NodeCategory::Class | NodeCategory::Wrapper)
+    )
+}
+
 fn extract_wrapper(
-    output: &mut String,
+    output: &mut SourceLines,
     node: Node,
     source: &[u8],
     language: Language,
+    rules: &Rules,
+    config: &SkeletonConfig,
     depth: usize,
 ) {
     let indent = "    ".repeat(depth);
@@ -414,31 +711,34 @@ fn extract_wrapper(
 
         if kind == "decorator" || kind == "export" {
             let text = node_text(*child, source);
-            let _ = writeln!(output, "{}{}", indent, text.trim());
+            output.line(child.start_position().row, format!("{}{}", indent, text.trim()));
         }
     }
 
     for child in &children {
-        let kind = child.kind();
-
-        if language.definition_types().contains(&kind) {
-            extract_definition(output, *child, source, language, depth);
-        } else if language.class_types().contains(&kind) {
-            extract_class(output, *child, source, language, depth);
-        } else if language.constant_types().contains(&kind) {
-            extract_constant(output, *child, source, language, depth);
+        if rules.is(*child, NodeCategory::Definition) {
+            extract_definition(output, *child, source, language, rules, config, depth);
+        } else if rules.is(*child, NodeCategory::Class) {
+            extract_class(output, *child, source, language, rules, config, depth);
+        } else if rules.is(*child, NodeCategory::Constant) {
+            extract_constant(output, *child, source, language, rules, config, depth);
         }
     }
 }
 
 fn extract_constant(
-    output: &mut String,
+    output: &mut SourceLines,
     node: Node,
     source: &[u8],
     language: Language,
+    rules: &Rules,
+    config: &SkeletonConfig,
     depth: usize,
 ) {
     match language {
+        Language::Rust if config.filters_private() && node.kind() == "struct_item" => {
+            extract_public_fields(output, node, source, depth);
+        }
         Language::Python => {
             extract_python_constant(output, node, source, depth);
         }
@@ -456,8 +756,8 @@ fn extract_constant(
         _ => {
             if node.start_position().row == node.end_position().row {
                 append_node_text(output, node, source, depth);
-            } else if has_nested_definitions(node, language) {
-                extract_constant_with_definitions(output, node, source, language, depth);
+            } else if has_nested_definitions(node, rules) {
+                extract_constant_with_definitions(output, node, source, language, rules, depth);
             } else {
                 append_node_text(output, node, source, depth);
             }
@@ -465,8 +765,112 @@ fn extract_constant(
     }
 }
 
+// Prints a struct without its private fields, along with the attributes and
+// comments that precede each removed field
+fn extract_public_fields(output: &mut SourceLines, node: Node, source: &[u8], depth: usize) {
+    let Some(body) = node.child_by_field_name("body").filter(|body| body.kind() == "field_declaration_list") else {
+        append_node_text(output, node, source, depth);
+        return;
+    };
+
+    let mut removed: Vec<(usize, usize)> = Vec::new();
+    let mut pending: Option<usize> = None;
+    let mut cursor = body.walk();
+    let children: Vec<Node> = body.children(&mut cursor).collect();
+
+    for (index, child) in children.iter().enumerate() {
+        match child.kind() {
+            "attribute_item" | "line_comment" | "block_comment" => {
+                pending.get_or_insert(child.start_byte());
+            }
+            "field_declaration" if !is_public(*child, source, Language::Rust) => {
+                let start = pending.take().unwrap_or(child.start_byte());
+
+                let end = match children.get(index + 1) {
+                    Some(next) if next.kind() == "," => next.end_byte(),
+                    _ => child.end_byte(),
+                };
+
+                removed.push(whole_lines(source, start, end));
+            }
+            _ => pending = None,
+        }
+    }
+
+    let removed: Vec<(usize, usize, String)> = removed
+        .into_iter()
+        .map(|(start, end)| (start, end, String::new()))
+        .collect();
+
+    let indent = "    ".repeat(depth);
+
+    for (row, line) in splice_lines(node, source, &removed) {
+        output.line(row, format!("{}{}", indent, line));
+    }
+}
+
+// Rebuilds the node's text with byte ranges replaced, keeping the source row
+// each resulting line starts on
+fn splice_lines(node: Node, source: &[u8], replacements: &[(usize, usize, String)]) -> Vec<(Option<usize>, String)> {
+    let mut lines = vec![(None, String::new())];
+    let mut row = node.start_position().row;
+    let mut position = node.start_byte();
+
+    for (start, end, replacement) in replacements {
+        row = push_spliced(&mut lines, &String::from_utf8_lossy(&source[position..*start]), row);
+        push_spliced(&mut lines, replacement, row);
+
+        row += source[*start..*end].iter().filter(|byte| **byte == b'\n').count();
+        position = *end;
+    }
+
+    push_spliced(&mut lines, &String::from_utf8_lossy(&source[position..node.end_byte()]), row);
+
+    if lines.last().is_some_and(|(_, line)| line.is_empty()) {
+        lines.pop();
+    }
+
+    lines
+}
+
+fn push_spliced(lines: &mut Vec<(Option<usize>, String)>, text: &str, mut row: usize) -> usize {
+    for (index, part) in text.split('\n').enumerate() {
+        if index > 0 {
+            row += 1;
+            lines.push((None, String::new()));
+        }
+
+        let part = part.trim_end_matches('\r');
+
+        if let Some((line_row, line)) = lines.last_mut() {
+            if line.is_empty() && !part.is_empty() {
+                *line_row = Some(row);
+            }
+
+            line.push_str(part);
+        }
+    }
+
+    row
+}
+
+// Widens a removed range over the trailing spaces, and over the whole line
+// when nothing else shares it
+fn whole_lines(source: &[u8], start: usize, end: usize) -> (usize, usize) {
+    let is_space = |byte: &u8| matches!(byte, b' ' | b'\t' | b'\r');
+
+    let line_start = start - source[..start].iter().rev().take_while(|byte| is_space(byte)).count();
+    let end = end + source[end..].iter().take_while(|byte| is_space(byte)).count();
+
+    if (line_start == 0 || source[line_start - 1] == b'\n') && source.get(end) == Some(&b'\n') {
+        (line_start, end + 1)
+    } else {
+        (start, end)
+    }
+}
+
 fn extract_python_constant(
-    output: &mut String,
+    output: &mut SourceLines,
     node: Node,
     source: &[u8],
     depth: usize,
@@ -485,17 +889,15 @@ fn extract_python_constant(
     }
 }
 
-fn has_nested_definitions(node: Node, language: Language) -> bool {
+fn has_nested_definitions(node: Node, rules: &Rules) -> bool {
     let mut cursor = node.walk();
 
     for child in node.children(&mut cursor) {
-        let kind = child.kind();
-
-        if language.definition_types().contains(&kind) {
+        if rules.is(child, NodeCategory::Definition) {
             return true;
         }
 
-        if child.child_count() > 0 && has_nested_definitions(child, language) {
+        if child.child_count() > 0 && has_nested_definitions(child, rules) {
             return true;
         }
     }
@@ -504,39 +906,25 @@ fn has_nested_definitions(node: Node, language: Language) -> bool {
 }
 
 fn extract_constant_with_definitions(
-    output: &mut String,
+    output: &mut SourceLines,
     node: Node,
     source: &[u8],
     language: Language,
+    rules: &Rules,
     depth: usize,
 ) {
     let indent = "    ".repeat(depth);
     let mut replacements: Vec<(usize, usize, String)> = Vec::new();
 
-    collect_definition_skeletons(node, source, language, &mut replacements);
+    collect_definition_skeletons(node, source, language, rules, &mut replacements);
     replacements.sort_by_key(|r| r.0);
 
-    let node_start = node.start_byte();
-    let node_end = node.end_byte();
-    let mut result = String::new();
-    let mut pos = node_start;
-
-    for (start, end, skeleton) in &replacements {
-        let before = String::from_utf8_lossy(&source[pos..*start]);
-        result.push_str(&before);
-        result.push_str(skeleton);
-        pos = *end;
-    }
-
-    let remaining = String::from_utf8_lossy(&source[pos..node_end]);
-    result.push_str(&remaining);
-
-    for line in result.lines() {
+    for (row, line) in splice_lines(node, source, &replacements) {
         if line.trim().is_empty() {
             continue;
         }
 
-        let _ = writeln!(output, "{}{}", indent, line);
+        output.line(row, format!("{}{}", indent, line));
     }
 }
 
@@ -544,24 +932,23 @@ fn collect_definition_skeletons(
     node: Node,
     source: &[u8],
     language: Language,
+    rules: &Rules,
     skeletons: &mut Vec<(usize, usize, String)>,
 ) {
     let mut cursor = node.walk();
 
     for child in node.children(&mut cursor) {
-        let kind = child.kind();
-
-        if language.definition_types().contains(&kind) {
-            let skeleton = build_definition_skeleton(child, source, language);
+        if rules.is(child, NodeCategory::Definition) {
+            let skeleton = build_definition_skeleton(child, source, language, rules);
             skeletons.push((child.start_byte(), child.end_byte(), skeleton));
         } else if child.child_count() > 0 {
-            collect_definition_skeletons(child, source, language, skeletons);
+            collect_definition_skeletons(child, source, language, rules, skeletons);
         }
     }
 }
 
-fn build_definition_skeleton(node: Node, source: &[u8], language: Language) -> String {
-    if let Some(body) = find_body(node, language) {
+fn build_definition_skeleton(node: Node, source: &[u8], language: Language, rules: &Rules) -> String {
+    if let Some(body) = rules.body(node) {
         let sig_start = node.start_byte();
         let sig_end = body.start_byte();
         let sig_text = String::from_utf8_lossy(&source[sig_start..sig_end]);
@@ -574,7 +961,7 @@ fn build_definition_skeleton(node: Node, source: &[u8], language: Language) -> S
 }
 
 fn append_collapsed_assignment(
-    output: &mut String,
+    output: &mut SourceLines,
     node: Node,
     source: &[u8],
     depth: usize,
@@ -582,28 +969,257 @@ fn append_collapsed_assignment(
     let indent = "    ".repeat(depth);
     let text = node_text(node, source);
     let first_line = text.lines().next().unwrap_or("");
+    let row = node.start_position().row;
 
     if let Some(paren_pos) = first_line.find('(') {
-        let _ = writeln!(output, "{}{}...)", indent, &first_line[..paren_pos + 1]);
+        output.line(row, format!("{}{}...)", indent, &first_line[..paren_pos + 1]));
     } else if let Some(bracket_pos) = first_line.find('[') {
-        let _ = writeln!(output, "{}{}...]", indent, &first_line[..bracket_pos + 1]);
+        output.line(row, format!("{}{}...]", indent, &first_line[..bracket_pos + 1]));
     } else if let Some(brace_pos) = first_line.find('{') {
-        let _ = writeln!(output, "{}{}...}}", indent, &first_line[..brace_pos + 1]);
+        output.line(row, format!("{}{}...}}", indent, &first_line[..brace_pos + 1]));
     } else {
-        let _ = writeln!(output, "{}{} ...", indent, first_line.trim_end());
+        output.line(row, format!("{}{} ...", indent, first_line.trim_end()));
+    }
+}
+
+fn is_public(node: Node, source: &[u8], language: Language) -> bool {
+    match language {
+        Language::JavaScript => match node.kind() {
+            "export_statement" => true,
+            "method_definition" => node
+                .child_by_field_name("name")
+                .is_some_and(|name| !node_text(name, source).starts_with(['#', '_'])),
+            _ => false,
+        },
+        Language::Python if matches!(node.kind(), "import_statement" | "import_from_statement") => false,
+        Language::Python => match definition_name(node, source) {
+            Some(name) => !name.starts_with('_') || (name.starts_with("__") && name.ends_with("__")),
+            None => true,
+        },
+        Language::Rust => {
+            if node.kind() == "impl_item" {
+                return node.child_by_field_name("trait").is_some() || is_public_impl_type(node, source);
+            }
+
+            let mut cursor = node.walk();
+
+            node.children(&mut cursor)
+                .any(|child| child.kind() == "visibility_modifier" && node_text(child, source) == "pub")
+        }
+        Language::Zig => node_text(node, source).starts_with("pub "),
+        _ => true,
+    }
+}
+
+// An inherent impl follows the type it is for, looked up among the items of
+// the same module. Types declared elsewhere are assumed to be public.
+fn is_public_impl_type(node: Node, source: &[u8]) -> bool {
+    let Some(mut type_node) = node.child_by_field_name("type") else {
+        return true;
+    };
+
+    while let Some(inner) = type_node.child_by_field_name("type") {
+        type_node = inner;
+    }
+
+    if type_node.kind() != "type_identifier" {
+        return true;
+    }
+
+    let name = node_text(type_node, source);
+    let Some(scope) = node.parent() else {
+        return true;
+    };
+
+    let mut cursor = scope.walk();
+
+    scope.named_children(&mut cursor)
+        .find(|item| {
+            matches!(item.kind(), "struct_item" | "enum_item" | "union_item" | "type_item")
+                && item.child_by_field_name("name").is_some_and(|item_name| node_text(item_name, source) == name)
+        })
+        .is_none_or(|item| is_public(item, source, Language::Rust))
+}
+
+fn is_public_container(body: Node) -> bool {
+    body.parent().is_some_and(|parent| {
+        parent.kind() == "trait_item"
+            || (parent.kind() == "impl_item" && parent.child_by_field_name("trait").is_some())
+    })
+}
+
+fn definition_name(node: Node, source: &[u8]) -> Option<String> {
+    match node.kind() {
+        "decorated_definition" => node
+            .child_by_field_name("definition")
+            .and_then(|definition| definition_name(definition, source)),
+        "expression_statement" => node
+            .named_child(0)
+            .filter(|child| child.kind() == "assignment")
+            .and_then(|assignment| assignment.child_by_field_name("left"))
+            .map(|left| node_text(left, source)),
+        _ => node
+            .child_by_field_name("name")
+            .map(|name| node_text(name, source)),
     }
 }
 
-fn append_node_text(output: &mut String, node: Node, source: &[u8], depth: usize) {
+fn is_doc_comment(node: Node, source: &[u8], language: Language) -> bool {
+    if !matches!(node.kind(), "comment" | "line_comment" | "block_comment") {
+        return false;
+    }
+
+    let text = node_text(node, source);
+
+    match language {
+        Language::Rust | Language::Zig => {
+            text.starts_with("///") || text.starts_with("//!")
+                || (text.starts_with("/**") && text != "/**/")
+                || text.starts_with("/*!")
+        }
+        Language::Css | Language::JavaScript => text.starts_with("/**") && text != "/**/",
+        _ => false,
+    }
+}
+
+fn is_inner_doc_comment(node: Node, source: &[u8]) -> bool {
+    let text = node_text(node, source);
+    text.starts_with("//!") || text.starts_with("/*!")
+}
+
+fn is_docstring(node: Node) -> bool {
+    node.kind() == "expression_statement"
+        && node.named_child_count() == 1
+        && node.named_child(0).is_some_and(|child| child.kind() == "string")
+}
+
+fn find_docstring(body: Node) -> Option<Node> {
+    let mut cursor = body.walk();
+
+    body.named_children(&mut cursor)
+        .find(|child| child.kind() != "comment")
+        .filter(|child| is_docstring(*child))
+}
+
+fn append_doc_comments(
+    output: &mut SourceLines,
+    nodes: &[Node],
+    source: &[u8],
+    config: &SkeletonConfig,
+    depth: usize,
+) {
     let indent = "    ".repeat(depth);
+    let mut lines: Vec<(Option<usize>, String)> = Vec::new();
+
+    for node in nodes {
+        let row = node.start_position().row;
+
+        for (index, line) in node_text(*node, source).lines().enumerate() {
+            let line = line.trim();
+
+            if line.starts_with('*') {
+                lines.push((Some(row + index), format!(" {}", line)));
+            } else {
+                lines.push((Some(row + index), line.to_string()));
+            }
+        }
+    }
+
+    if config.doc_summary {
+        truncate_doc_lines(&mut lines);
+    }
+
+    for (row, line) in lines {
+        if line.is_empty() {
+            output.blank();
+        } else {
+            output.line(row, format!("{}{}", indent, line));
+        }
+    }
+}
+
+fn truncate_doc_lines(lines: &mut Vec<(Option<usize>, String)>) {
+    let closing = match lines.first().map(|(_, line)| line) {
+        Some(first) if first.starts_with("/*") => Some(" */".to_string()),
+        Some(first) => {
+            let quotes = first.trim_start_matches(|c: char| c.is_ascii_alphabetic());
+
+            if quotes.starts_with("\"\"\"") {
+                Some("\"\"\"".to_string())
+            } else if quotes.starts_with("'''") {
+                Some("'''".to_string())
+            } else {
+                None
+            }
+        }
+        None => None,
+    };
+
+    let mut seen_content = false;
+
+    for index in 0..lines.len() {
+        let content = lines[index]
+            .1
+            .trim()
+            .trim_start_matches(['/', '*', '!', '"', '\''])
+            .trim_end_matches(['/', '*', '"', '\''])
+            .trim();
+
+        if !content.is_empty() {
+            seen_content = true;
+            continue;
+        }
+
+        if seen_content && index > 0 {
+            lines.truncate(index);
+
+            if let Some(closing) = closing {
+                let is_closed = lines.len() > 1
+                    && lines.last().is_some_and(|(_, line)| line.trim_end().ends_with(closing.trim()));
+
+                if !is_closed {
+                    lines.push((None, closing));
+                }
+            }
+
+            return;
+        }
+    }
+}
+
+fn append_node_text(output: &mut SourceLines, node: Node, source: &[u8], depth: usize) {
+    let indent = "    ".repeat(depth);
+    let row = node.start_position().row;
     let text = node_text(node, source);
 
-    for line in text.lines() {
-        let _ = writeln!(output, "{}{}", indent, line);
+    for (index, line) in text.lines().enumerate() {
+        output.line(row + index, format!("{}{}", indent, line));
+    }
+}
+
+fn append_verbatim(output: &mut SourceLines, node: Node, source: &[u8], depth: usize) {
+    let indent = "    ".repeat(depth);
+    let column = node.start_position().column;
+    let row = node.start_position().row;
+    let text = node_text(node, source);
+
+    for (index, line) in text.lines().enumerate() {
+        let line = if index == 0 {
+            line
+        } else {
+            let leading = line.len() - line.trim_start().len();
+            &line[leading.min(column)..]
+        };
+
+        if line.trim().is_empty() {
+            output.blank();
+        } else {
+            output.line(row + index, format!("{}{}", indent, line));
+        }
     }
 }
 
-fn node_text(node: Node, source: &[u8]) -> String {
+pub(crate) fn node_text(node: Node, source: &[u8]) -> String {
     let start = node.start_byte();
     let end = node.end_byte();
 
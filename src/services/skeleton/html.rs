@@ -0,0 +1,257 @@
+use tree_sitter::Node;
+
+use crate::services::filesystem::numbering::SourceLines;
+
+use super::generator::{extract_skeleton, SkeletonConfig};
+use super::language::Language;
+
+const KEPT_ATTRIBUTES: &[&str] = &[
+    "action", "charset", "class", "for", "href", "id", "lang",
+    "method", "name", "rel", "role", "src", "type",
+];
+
+const SCRIPT_TYPES: &[&str] = &[
+    "application/javascript", "module", "text/javascript",
+];
+
+pub fn extract_document(
+    output: &mut SourceLines,
+    node: Node,
+    source: &[u8],
+    config: &SkeletonConfig,
+    depth: usize,
+) {
+    let mut cursor = node.walk();
+    let children: Vec<Node> = node.children(&mut cursor).collect();
+    let mut pending_text = false;
+
+    for child in children {
+        match child.kind() {
+            "doctype" => {
+                let indent = "    ".repeat(depth);
+                output.line(child.start_position().row, format!("{}{}", indent, node_text(child, source).trim()));
+            }
+            "element" => {
+                flush_text(output, &mut pending_text, depth);
+                extract_element(output, child, source, config, depth);
+            }
+            "script_element" => {
+                flush_text(output, &mut pending_text, depth);
+                extract_raw_element(output, child, source, Language::JavaScript, config, depth);
+            }
+            "style_element" => {
+                flush_text(output, &mut pending_text, depth);
+                extract_raw_element(output, child, source, Language::Css, config, depth);
+            }
+            "text" | "entity" if !node_text(child, source).trim().is_empty() => {
+                pending_text = true;
+            }
+            _ => {}
+        }
+    }
+
+    flush_text(output, &mut pending_text, depth);
+}
+
+fn extract_element(
+    output: &mut SourceLines,
+    node: Node,
+    source: &[u8],
+    config: &SkeletonConfig,
+    depth: usize,
+) {
+    let indent = "    ".repeat(depth);
+    let mut cursor = node.walk();
+    let children: Vec<Node> = node.children(&mut cursor).collect();
+
+    let Some(tag) = children
+        .iter()
+        .find(|child| matches!(child.kind(), "start_tag" | "self_closing_tag"))
+    else {
+        return;
+    };
+
+    let name = tag_name(*tag, source);
+    let opening = build_tag(*tag, source);
+    let row = tag.start_position().row;
+
+    if tag.kind() == "self_closing_tag" {
+        output.line(row, format!("{}{}", indent, opening));
+        return;
+    }
+
+    let Some(end_tag) = children.iter().find(|child| child.kind() == "end_tag") else {
+        output.line(row, format!("{}{}", indent, opening));
+        return;
+    };
+
+    let has_elements = children.iter().any(|child| {
+        matches!(child.kind(), "element" | "script_element" | "style_element")
+    });
+
+    if !has_elements {
+        let has_text = children.iter().any(|child| {
+            matches!(child.kind(), "text" | "entity")
+                && !node_text(*child, source).trim().is_empty()
+        });
+
+        let content = if has_text { "..." } else { "" };
+        output.line(row, format!("{}{}{}</{}>", indent, opening, content, name));
+        return;
+    }
+
+    output.line(row, format!("{}{}", indent, opening));
+    extract_document(output, node, source, config, depth + 1);
+    output.line(end_tag.start_position().row, format!("{}</{}>", indent, name));
+}
+
+fn extract_raw_element(
+    output: &mut SourceLines,
+    node: Node,
+    source: &[u8],
+    language: Language,
+    config: &SkeletonConfig,
+    depth: usize,
+) {
+    let indent = "    ".repeat(depth);
+    let mut cursor = node.walk();
+    let children: Vec<Node> = node.children(&mut cursor).collect();
+
+    let Some(tag) = children.iter().find(|child| child.kind() == "start_tag") else {
+        return;
+    };
+
+    let name = tag_name(*tag, source);
+    let opening = build_tag(*tag, source);
+    let row = tag.start_position().row;
+
+    let raw_text = children.iter().find(|child| child.kind() == "raw_text");
+    let raw = raw_text.map(|child| node_text(*child, source)).unwrap_or_default();
+
+    if raw.trim().is_empty() {
+        output.line(row, format!("{}{}</{}>", indent, opening, name));
+        return;
+    }
+
+    let skeleton = if is_parseable_raw(*tag, source, language) {
+        extract_skeleton(&raw, language, config)
+    } else {
+        None
+    };
+
+    match skeleton {
+        Some(skeleton) => {
+            let inner = "    ".repeat(depth + 1);
+            let offset = raw_text.map_or(row, |raw| raw.start_position().row);
+            let end_row = children.iter().find(|child| child.kind() == "end_tag").map(|end| end.start_position().row);
+
+            output.line(row, format!("{}{}", indent, opening));
+            output.extend(skeleton, &inner, offset);
+            output.line(end_row, format!("{}</{}>", indent, name));
+        }
+        None => {
+            output.line(row, format!("{}{}...</{}>", indent, opening, name));
+        }
+    }
+}
+
+fn is_parseable_raw(tag: Node, source: &[u8], language: Language) -> bool {
+    if language != Language::JavaScript {
+        return true;
+    }
+
+    match attribute_value(tag, source, "type") {
+        Some(value) => SCRIPT_TYPES.contains(&value.to_lowercase().as_str()),
+        None => true,
+    }
+}
+
+fn build_tag(tag: Node, source: &[u8]) -> String {
+    let mut result = format!("<{}", tag_name(tag, source));
+    let mut cursor = tag.walk();
+
+    for child in tag.children(&mut cursor) {
+        if child.kind() != "attribute" {
+            continue;
+        }
+
+        let Some(name) = attribute_name(child, source) else {
+            continue;
+        };
+
+        if !KEPT_ATTRIBUTES.contains(&name.to_lowercase().as_str()) {
+            continue;
+        }
+
+        result.push(' ');
+        result.push_str(node_text(child, source).trim());
+    }
+
+    if tag.kind() == "self_closing_tag" {
+        result.push_str(" />");
+    } else {
+        result.push('>');
+    }
+
+    result
+}
+
+fn tag_name(tag: Node, source: &[u8]) -> String {
+    let mut cursor = tag.walk();
+
+    tag.children(&mut cursor)
+        .find(|child| child.kind() == "tag_name")
+        .map(|child| node_text(child, source))
+        .unwrap_or_default()
+}
+
+fn attribute_name(attribute: Node, source: &[u8]) -> Option<String> {
+    let mut cursor = attribute.walk();
+
+    attribute
+        .children(&mut cursor)
+        .find(|child| child.kind() == "attribute_name")
+        .map(|child| node_text(child, source))
+}
+
+fn attribute_value(tag: Node, source: &[u8], name: &str) -> Option<String> {
+    let mut cursor = tag.walk();
+
+    for attribute in tag.children(&mut cursor) {
+        if attribute.kind() != "attribute" {
+            continue;
+        }
+
+        if !attribute_name(attribute, source).is_some_and(|n| n.eq_ignore_ascii_case(name)) {
+            continue;
+        }
+
+        let mut inner = attribute.walk();
+
+        for child in attribute.children(&mut inner) {
+            match child.kind() {
+                "attribute_value" => return Some(node_text(child, source)),
+                "quoted_attribute_value" => {
+                    let text = node_text(child, source);
+                    return Some(text.trim_matches(|c| c == '"' || c == '\'').to_string());
+                }
+                _ => {}
+            }
+        }
+
+        return Some(String::new());
+    }
+
+    None
+}
+
+fn flush_text(output: &mut SourceLines, pending: &mut bool, depth: usize) {
+    if *pending {
+        output.line(None, format!("{}...", "    ".repeat(depth)));
+        *pending = false;
+    }
+}
+
+fn node_text(node: Node, source: &[u8]) -> String {
+    String::from_utf8_lossy(&source[node.start_byte()..node.end_byte()]).to_string()
+}
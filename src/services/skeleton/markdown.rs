@@ -0,0 +1,246 @@
+use tree_sitter::Node;
+
+use crate::services::filesystem::numbering::SourceLines;
+
+use super::generator::{extract_skeleton, parse_markdown_inline, SkeletonConfig};
+use super::language::Language;
+
+pub fn extract_document(output: &mut SourceLines, root: Node, source: &[u8], config: &SkeletonConfig) {
+    let mut links: Vec<String> = Vec::new();
+
+    for child in named_children(root) {
+        match child.kind() {
+            "minus_metadata" => extract_metadata(output, child, source, config, Language::Yaml, "---"),
+            "plus_metadata" => extract_metadata(output, child, source, config, Language::Toml, "+++"),
+            "section" => extract_section(output, child, source, &mut links),
+            _ => {}
+        }
+    }
+}
+
+fn extract_metadata(
+    output: &mut SourceLines,
+    node: Node,
+    source: &[u8],
+    config: &SkeletonConfig,
+    language: Language,
+    delimiter: &str,
+) {
+    let text = node_text(node, source);
+    let row = node.start_position().row;
+    let lines: Vec<&str> = text.lines().collect();
+
+    let body: String = lines
+        .iter()
+        .filter(|line| line.trim() != delimiter)
+        .copied()
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    output.line(row, delimiter);
+
+    if let Some(skeleton) = extract_skeleton(&body, language, config) {
+        output.extend(skeleton, "", row + 1);
+    }
+
+    output.line(row + lines.len().saturating_sub(1), delimiter);
+}
+
+fn extract_section(
+    output: &mut SourceLines,
+    node: Node,
+    source: &[u8],
+    links: &mut Vec<String>,
+) {
+    let mut summary_pending = true;
+
+    for child in named_children(node) {
+        match child.kind() {
+            "section" => extract_section(output, child, source, links),
+            "atx_heading" | "setext_heading" => {
+                if !output.is_empty() {
+                    output.blank();
+                }
+
+                output.line(child.start_position().row, format_heading(child, source));
+                summary_pending = true;
+            }
+            "paragraph" if summary_pending => {
+                let sentence = first_sentence(&node_text(child, source));
+
+                if !sentence.is_empty() {
+                    output.line(child.start_position().row, sentence);
+                }
+
+                summary_pending = false;
+                extract_links(output, child, source, links);
+            }
+            "fenced_code_block" => extract_code_block(output, child, source),
+            "link_reference_definition" => {
+                output.line(child.start_position().row, node_text(child, source).trim());
+            }
+            _ => extract_links(output, child, source, links),
+        }
+    }
+}
+
+fn format_heading(node: Node, source: &[u8]) -> String {
+    let content = node
+        .child_by_field_name("heading_content")
+        .map(|content| collapse_whitespace(&node_text(content, source)))
+        .unwrap_or_default();
+
+    let mut level = 1;
+    let mut cursor = node.walk();
+
+    for child in node.children(&mut cursor) {
+        let kind = child.kind();
+
+        if let Some(digit) = kind
+            .strip_prefix("atx_h")
+            .and_then(|rest| rest.strip_suffix("_marker"))
+        {
+            level = digit.parse().unwrap_or(1);
+        } else if kind == "setext_h2_underline" {
+            level = 2;
+        }
+    }
+
+    format!("{} {}", "#".repeat(level), content)
+}
+
+fn extract_code_block(output: &mut SourceLines, node: Node, source: &[u8]) {
+    let language = named_children(node)
+        .into_iter()
+        .find(|child| child.kind() == "info_string")
+        .and_then(|info| {
+            named_children(info)
+                .into_iter()
+                .find(|child| child.kind() == "language")
+        })
+        .map(|language| node_text(language, source))
+        .unwrap_or_default();
+
+    let closing = named_children(node)
+        .into_iter()
+        .rfind(|child| child.kind() == "fenced_code_block_delimiter")
+        .map(|delimiter| delimiter.start_position().row);
+
+    output.line(node.start_position().row, format!("```{}", language));
+    output.line(None, "...");
+    output.line(closing, "```");
+}
+
+fn extract_links(
+    output: &mut SourceLines,
+    node: Node,
+    source: &[u8],
+    links: &mut Vec<String>,
+) {
+    if node.kind() == "inline" {
+        let text = node_text(node, source);
+
+        let Some(tree) = parse_markdown_inline(&text) else {
+            return;
+        };
+
+        collect_inline_links(output, tree.root_node(), text.as_bytes(), node.start_position().row, links);
+        return;
+    }
+
+    for child in named_children(node) {
+        extract_links(output, child, source, links);
+    }
+}
+
+// Rows in the inline tree count from `offset`, the row the inline content starts on
+fn collect_inline_links(
+    output: &mut SourceLines,
+    node: Node,
+    source: &[u8],
+    offset: usize,
+    links: &mut Vec<String>,
+) {
+    let row = offset + node.start_position().row;
+
+    match node.kind() {
+        "inline_link" => {
+            let children = named_children(node);
+
+            let label = children
+                .iter()
+                .find(|child| child.kind() == "link_text")
+                .map(|child| collapse_whitespace(&node_text(*child, source)))
+                .unwrap_or_default();
+
+            let Some(destination) = children
+                .iter()
+                .find(|child| child.kind() == "link_destination")
+                .map(|child| node_text(*child, source))
+            else {
+                return;
+            };
+
+            push_link(output, links, row, &label, &destination);
+        }
+        "uri_autolink" => {
+            let destination = node_text(node, source);
+            let destination = destination.trim_start_matches('<').trim_end_matches('>');
+
+            push_link(output, links, row, destination, destination);
+        }
+        _ => {
+            for child in named_children(node) {
+                collect_inline_links(output, child, source, offset, links);
+            }
+        }
+    }
+}
+
+fn push_link(output: &mut SourceLines, links: &mut Vec<String>, row: usize, label: &str, destination: &str) {
+    if links.iter().any(|link| link == destination) {
+        return;
+    }
+
+    links.push(destination.to_string());
+
+    output.line(row, format!("- [{}]({})", label, destination));
+}
+
+fn first_sentence(text: &str) -> String {
+    let collapsed = collapse_whitespace(text);
+    let chars: Vec<(usize, char)> = collapsed.char_indices().collect();
+
+    for (position, (index, c)) in chars.iter().enumerate() {
+        if !matches!(c, '.' | '!' | '?') {
+            continue;
+        }
+
+        let end = index + c.len_utf8();
+
+        let at_boundary = match (chars.get(position + 1), chars.get(position + 2)) {
+            (None, _) => true,
+            (Some((_, space)), Some((_, next))) => space.is_whitespace() && next.is_uppercase(),
+            _ => false,
+        };
+
+        if at_boundary {
+            return collapsed[..end].to_string();
+        }
+    }
+
+    collapsed
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn named_children(node: Node) -> Vec<Node> {
+    let mut cursor = node.walk();
+    node.named_children(&mut cursor).collect()
+}
+
+fn node_text(node: Node, source: &[u8]) -> String {
+    String::from_utf8_lossy(&source[node.start_byte()..node.end_byte()]).to_string()
+}
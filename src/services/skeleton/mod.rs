@@ -1,5 +1,9 @@
+pub mod data;
 pub mod generator;
+pub mod html;
 pub mod language;
+pub mod markdown;
+pub mod query;
 
 pub use generator::SkeletonGenerator;
 pub use language::Language;
@@ -0,0 +1,9 @@
+(import_statement) @import
+(charset_statement) @import
+(namespace_statement) @import
+
+(media_statement (block) @body) @container
+(supports_statement (block) @body) @container
+
+(rule_set (block) @body) @definition
+(keyframes_statement (keyframe_block_list) @body) @definition
@@ -0,0 +1,12 @@
+(import_statement) @import
+
+(export_statement) @wrapper
+
+(class_declaration body: (_) @body) @container
+
+(function_declaration body: (_) @body) @definition
+(method_definition body: (_) @body) @definition
+(arrow_function body: (_) @body) @definition
+
+(lexical_declaration) @constant
+(variable_declaration) @constant
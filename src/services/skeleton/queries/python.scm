@@ -0,0 +1,10 @@
+(import_statement) @import
+(import_from_statement) @import
+
+(decorated_definition) @wrapper
+
+(class_definition body: (_) @body) @container
+
+(function_definition body: (_) @body) @definition
+
+(expression_statement) @constant
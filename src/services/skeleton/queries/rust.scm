@@ -0,0 +1,15 @@
+(use_declaration) @import
+(extern_crate_declaration) @import
+
+(impl_item body: (_) @body) @container
+(trait_item body: (_) @body) @container
+
+(function_item body: (_) @body) @definition
+
+(const_item) @constant
+(static_item) @constant
+(type_item) @constant
+(struct_item) @constant
+(enum_item) @constant
+(mod_item) @constant
+(macro_definition) @constant
@@ -0,0 +1,4 @@
+(function_declaration body: (_) @body) @definition
+(test_declaration) @definition
+
+(variable_declaration) @constant
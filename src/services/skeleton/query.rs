@@ -0,0 +1,180 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+use tree_sitter::{Node, Query, QueryCursor, StreamingIterator};
+
+use crate::constants::APP_NAME;
+
+use super::language::Language;
+
+static QUERIES: OnceLock<Vec<LoadedQuery>> = OnceLock::new();
+
+struct LoadedQuery {
+    custom: bool,
+    language: Language,
+    query: Query,
+}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum NodeCategory {
+    Class,
+    Constant,
+    Definition,
+    Import,
+    Wrapper,
+}
+
+impl NodeCategory {
+    fn from_capture(name: &str) -> Option<Self> {
+        match name {
+            "container" => Some(Self::Class),
+            "constant" => Some(Self::Constant),
+            "definition" => Some(Self::Definition),
+            "import" => Some(Self::Import),
+            "wrapper" => Some(Self::Wrapper),
+            _ => None,
+        }
+    }
+}
+
+pub struct Rules<'tree> {
+    bodies: HashMap<usize, Node<'tree>>,
+    categories: HashMap<usize, NodeCategory>,
+}
+
+impl<'tree> Rules<'tree> {
+    pub fn collect(root: Node<'tree>, source: &[u8], language: Language) -> Option<Self> {
+        let query = query(language)?;
+        let names = query.capture_names();
+
+        let mut bodies = HashMap::new();
+        let mut categories = HashMap::new();
+        let mut cursor = QueryCursor::new();
+        let mut matches = cursor.matches(query, root, source);
+
+        while let Some(found) = matches.next() {
+            let mut target: Option<(Node<'tree>, NodeCategory)> = None;
+            let mut body: Option<Node<'tree>> = None;
+
+            for capture in found.captures {
+                let name = names[capture.index as usize];
+
+                if name == "body" {
+                    body = Some(capture.node);
+                } else if let Some(category) = NodeCategory::from_capture(name) {
+                    target = Some((capture.node, category));
+                }
+            }
+
+            let Some((node, category)) = target else {
+                continue;
+            };
+
+            categories.entry(node.id()).or_insert(category);
+
+            if let Some(body) = body {
+                bodies.entry(node.id()).or_insert(body);
+            }
+        }
+
+        Some(Self { bodies, categories })
+    }
+
+    pub fn body(&self, node: Node) -> Option<Node<'tree>> {
+        self.bodies.get(&node.id()).copied()
+    }
+
+    pub fn category(&self, node: Node) -> Option<NodeCategory> {
+        self.categories.get(&node.id()).copied()
+    }
+
+    pub fn is(&self, node: Node, category: NodeCategory) -> bool {
+        self.category(node) == Some(category)
+    }
+}
+
+fn query(language: Language) -> Option<&'static Query> {
+    loaded(language).map(|loaded| &loaded.query)
+}
+
+// Languages with a user query skip their built-in document extractor
+pub fn has_custom_query(language: Language) -> bool {
+    loaded(language).is_some_and(|loaded| loaded.custom)
+}
+
+fn loaded(language: Language) -> Option<&'static LoadedQuery> {
+    QUERIES
+        .get_or_init(load_queries)
+        .iter()
+        .find(|loaded| loaded.language == language)
+}
+
+fn load_queries() -> Vec<LoadedQuery> {
+    let mut queries = Vec::new();
+
+    warn_unknown_queries();
+
+    for language in Language::all() {
+        let grammar = language.grammar();
+
+        let custom = queries_dir()
+            .map(|dir| dir.join(format!("{}.scm", language.id())))
+            .and_then(|path| fs::read_to_string(path).ok());
+
+        if let Some(source) = custom {
+            match Query::new(&grammar, &source) {
+                Ok(query) => {
+                    queries.push(LoadedQuery { custom: true, language: *language, query });
+                    continue;
+                }
+                Err(error) => {
+                    eprintln!("Invalid {} skeleton query: {}", language.name(), error);
+                }
+            }
+        }
+
+        if let Some(source) = builtin_query(*language) {
+            match Query::new(&grammar, source) {
+                Ok(query) => queries.push(LoadedQuery { custom: false, language: *language, query }),
+                Err(error) => eprintln!("Invalid built-in {} skeleton query: {}", language.name(), error),
+            }
+        }
+    }
+
+    queries
+}
+
+fn warn_unknown_queries() {
+    let Some(entries) = queries_dir().and_then(|dir| fs::read_dir(dir).ok()) else {
+        return;
+    };
+
+    for path in entries.flatten().map(|entry| entry.path()) {
+        if path.extension().is_none_or(|extension| extension != "scm") {
+            continue;
+        }
+
+        let id = path.file_stem().and_then(|stem| stem.to_str()).unwrap_or_default();
+
+        if !Language::all().iter().any(|language| language.id() == id) {
+            eprintln!("Ignoring skeleton query {}: '{}' is not a supported language", path.display(), id);
+        }
+    }
+}
+
+fn builtin_query(language: Language) -> Option<&'static str> {
+    match language {
+        Language::Css => Some(include_str!("queries/css.scm")),
+        Language::JavaScript => Some(include_str!("queries/javascript.scm")),
+        Language::Python => Some(include_str!("queries/python.scm")),
+        Language::Rust => Some(include_str!("queries/rust.scm")),
+        Language::Zig => Some(include_str!("queries/zig.scm")),
+        _ => None,
+    }
+}
+
+pub fn queries_dir() -> Option<PathBuf> {
+    dirs::data_local_dir().map(|dir| dir.join(APP_NAME.to_lowercase()).join("queries"))
+}
@@ -0,0 +1,147 @@
+use crate::services::filesystem::numbering::SourceLines;
+use crate::services::skeleton::language::Language;
+
+use super::index::{index_source, Symbol};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SymbolQuery {
+    pub name: String,
+    pub qualifier: Option<String>,
+}
+
+impl SymbolQuery {
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.trim();
+
+        let (qualifier, name) = match text.rsplit_once("::").or_else(|| text.rsplit_once('.')) {
+            Some((qualifier, name)) => {
+                let qualifier = qualifier.rsplit(['.', ':']).next().unwrap_or(qualifier);
+                (Some(qualifier.to_string()), name)
+            }
+            None => (None, text),
+        };
+
+        if name.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            name: name.to_string(),
+            qualifier: qualifier.filter(|qualifier| !qualifier.is_empty()),
+        })
+    }
+
+    pub fn matches(&self, symbol: &Symbol) -> bool {
+        if symbol.name != self.name {
+            return false;
+        }
+
+        let Some(ref qualifier) = self.qualifier else {
+            return true;
+        };
+
+        symbol.parent.as_deref().is_some_and(|parent| {
+            parent == qualifier || parent.ends_with(&format!(" for {}", qualifier))
+        })
+    }
+}
+
+pub fn extract_symbols(
+    content: &str,
+    language: Language,
+    queries: &[SymbolQuery],
+) -> Vec<(String, SourceLines)> {
+    let symbols = index_source(content, language, "");
+    let separator = if language == Language::Rust { "::" } else { "." };
+
+    let mut extracted = Vec::new();
+
+    for (index, symbol) in symbols.iter().enumerate() {
+        if !queries.iter().any(|query| query.matches(symbol)) {
+            continue;
+        }
+
+        let ancestors = enclosing_symbols(&symbols[..index], symbol);
+
+        let mut source = SourceLines::new();
+        let mut closers = Vec::new();
+
+        for ancestor in &ancestors {
+            let (header, closer) = container_header(content, ancestor);
+            source.line(row_at(content, ancestor.span.start), header);
+            closers.extend(closer);
+        }
+
+        let start = line_start(content, symbol.span.start);
+        source.line(row_at(content, start), content[start..line_end(content, symbol.span.end)].trim_end());
+
+        for (row, closer) in closers.into_iter().rev() {
+            source.line(row, closer);
+        }
+
+        let mut label: Vec<&str> = ancestors.iter().map(|ancestor| ancestor.name.as_str()).collect();
+        label.push(&symbol.name);
+
+        extracted.push((label.join(separator), source));
+    }
+
+    extracted
+}
+
+fn enclosing_symbols<'a>(preceding: &'a [Symbol], symbol: &Symbol) -> Vec<&'a Symbol> {
+    let mut ancestors = Vec::new();
+    let mut depth = symbol.depth;
+
+    for candidate in preceding.iter().rev() {
+        if depth == 0 {
+            break;
+        }
+
+        let contains = candidate.span.start <= symbol.span.start && candidate.span.end >= symbol.span.end;
+
+        if candidate.depth < depth && contains {
+            ancestors.push(candidate);
+            depth = candidate.depth;
+        }
+    }
+
+    ancestors.reverse();
+    ancestors
+}
+
+fn container_header(content: &str, container: &Symbol) -> (String, Option<(usize, String)>) {
+    let start = line_start(content, container.span.start);
+
+    let header_end = match container.body_start {
+        Some(body_start) => body_start,
+        None => line_end(content, container.span.start),
+    };
+
+    let mut header = content[start..header_end].trim_end().to_string();
+    let opens_brace = content.as_bytes().get(header_end) == Some(&b'{');
+
+    if opens_brace {
+        header.push_str(" {");
+    }
+
+    if !opens_brace && !header.ends_with('{') {
+        return (header, None);
+    }
+
+    let closer_start = line_start(content, container.span.end.saturating_sub(1));
+    let closer = content[closer_start..line_end(content, container.span.end)].trim_end().to_string();
+
+    (header, Some((row_at(content, closer_start), closer)))
+}
+
+fn row_at(content: &str, offset: usize) -> usize {
+    content[..offset].bytes().filter(|byte| *byte == b'\n').count()
+}
+
+fn line_start(content: &str, offset: usize) -> usize {
+    content[..offset].rfind('\n').map_or(0, |index| index + 1)
+}
+
+fn line_end(content: &str, offset: usize) -> usize {
+    content[offset..].find('\n').map_or(content.len(), |index| offset + index)
+}
@@ -0,0 +1,342 @@
+use std::fs;
+use std::ops::Range;
+use std::path::Path;
+
+use tree_sitter::Node;
+
+use crate::app::state::search::ParsedQuery;
+use crate::model::error::SwarmResult;
+use crate::model::options::Options;
+use crate::services::filesystem::git::GitService;
+use crate::services::skeleton::SkeletonGenerator;
+use crate::services::skeleton::generator::{node_text, parse, process_parallel};
+use crate::services::skeleton::language::Language;
+use crate::services::skeleton::query::{NodeCategory, Rules};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SymbolKind {
+    Class,
+    Enum,
+    Function,
+    Impl,
+    Macro,
+    Method,
+    Module,
+    Struct,
+    Trait,
+    Type,
+    Union,
+}
+
+impl SymbolKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Class => "class",
+            Self::Enum => "enum",
+            Self::Function => "fn",
+            Self::Impl => "impl",
+            Self::Macro => "macro",
+            Self::Method => "method",
+            Self::Module => "mod",
+            Self::Struct => "struct",
+            Self::Trait => "trait",
+            Self::Type => "type",
+            Self::Union => "union",
+        }
+    }
+
+    fn is_container(&self) -> bool {
+        matches!(
+            self,
+            Self::Class | Self::Enum | Self::Impl | Self::Struct | Self::Trait | Self::Union
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Symbol {
+    pub body_start: Option<usize>,
+    pub depth: usize,
+    pub end_line: usize,
+    pub kind: SymbolKind,
+    pub line: usize,
+    pub name: String,
+    pub parent: Option<String>,
+    pub path: String,
+    pub span: Range<usize>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct SymbolIndex {
+    symbols: Vec<Symbol>,
+}
+
+impl SymbolIndex {
+    pub fn build(
+        paths: &[String],
+        options: &Options,
+        git_service: Option<&GitService>,
+        query: Option<&ParsedQuery>,
+    ) -> SwarmResult<Self> {
+        Self::build_with_progress(paths, options, git_service, query, &mut |_, _| {})
+    }
+
+    pub fn build_with_progress(
+        paths: &[String],
+        options: &Options,
+        git_service: Option<&GitService>,
+        query: Option<&ParsedQuery>,
+        progress: &mut dyn FnMut(usize, usize),
+    ) -> SwarmResult<Self> {
+        let files = SkeletonGenerator::new().resolve_files(paths, options, git_service, query)?;
+        let mut symbols = Vec::new();
+
+        process_parallel(&files, index_file, progress, &mut |file_symbols| symbols.extend(file_symbols));
+
+        Ok(Self { symbols })
+    }
+
+    pub fn file_count(&self) -> usize {
+        let mut count = 0;
+        let mut previous: Option<&str> = None;
+
+        for symbol in &self.symbols {
+            if previous != Some(symbol.path.as_str()) {
+                count += 1;
+                previous = Some(symbol.path.as_str());
+            }
+        }
+
+        count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.symbols.len()
+    }
+
+    pub fn symbols(&self) -> &[Symbol] {
+        &self.symbols
+    }
+}
+
+struct Scope<'a> {
+    depth: usize,
+    kind: Option<SymbolKind>,
+    name: Option<&'a str>,
+}
+
+fn index_file(path: &Path) -> Option<Vec<Symbol>> {
+    let language = Language::from_path(path)?;
+    let content = fs::read_to_string(path).ok()?;
+
+    let symbols = index_source(&content, language, &path.display().to_string());
+
+    if symbols.is_empty() {
+        return None;
+    }
+
+    Some(symbols)
+}
+
+pub fn index_source(content: &str, language: Language, path: &str) -> Vec<Symbol> {
+    let mut symbols = Vec::new();
+
+    let Some(tree) = parse(content, language) else {
+        return symbols;
+    };
+
+    let root = tree.root_node();
+    let source = content.as_bytes();
+
+    let Some(rules) = Rules::collect(root, source, language) else {
+        return symbols;
+    };
+
+    let scope = Scope {
+        depth: 0,
+        kind: None,
+        name: None,
+    };
+
+    collect_symbols(&mut symbols, root, source, &rules, path, &scope);
+
+    symbols
+}
+
+fn collect_symbols(
+    symbols: &mut Vec<Symbol>,
+    node: Node,
+    source: &[u8],
+    rules: &Rules,
+    path: &str,
+    scope: &Scope,
+) {
+    let mut cursor = node.walk();
+
+    for child in node.named_children(&mut cursor) {
+        let category = rules.category(child);
+
+        if category == Some(NodeCategory::Import) {
+            continue;
+        }
+
+        let Some(kind) = symbol_kind(child, scope) else {
+            if category != Some(NodeCategory::Definition) {
+                collect_symbols(symbols, child, source, rules, path, scope);
+            }
+
+            continue;
+        };
+
+        let Some(name) = symbol_name(child, source) else {
+            continue;
+        };
+
+        symbols.push(Symbol {
+            body_start: rules.body(child).map(|body| body.start_byte()),
+            depth: scope.depth,
+            end_line: child.end_position().row + 1,
+            kind,
+            line: child.start_position().row + 1,
+            name: name.clone(),
+            parent: scope.name.map(str::to_string),
+            path: path.to_string(),
+            span: symbol_span(child, source),
+        });
+
+        if category == Some(NodeCategory::Definition) {
+            continue;
+        }
+
+        let nested = Scope {
+            depth: scope.depth + 1,
+            kind: Some(kind),
+            name: Some(&name),
+        };
+
+        let body = rules.body(child).unwrap_or(child);
+        collect_symbols(symbols, body, source, rules, path, &nested);
+    }
+}
+
+fn symbol_span(node: Node, source: &[u8]) -> Range<usize> {
+    let mut outer = node;
+
+    if outer.kind() == "arrow_function"
+        && let Some(declaration) = outer.parent().and_then(|declarator| declarator.parent())
+    {
+        outer = declaration;
+    }
+
+    if let Some(parent) = outer.parent()
+        && matches!(parent.kind(), "decorated_definition" | "export_statement")
+    {
+        outer = parent;
+    }
+
+    let mut start = outer;
+
+    while let Some(previous) = start.prev_named_sibling() {
+        let attached = previous.end_position().row + 1 >= start.start_position().row;
+
+        if !attached || !is_leading_decoration(previous, source) {
+            break;
+        }
+
+        start = previous;
+    }
+
+    start.start_byte()..outer.end_byte()
+}
+
+fn is_leading_decoration(node: Node, source: &[u8]) -> bool {
+    match node.kind() {
+        "attribute_item" => true,
+        "comment" | "line_comment" | "block_comment" => {
+            let text = node_text(node, source);
+            (text.starts_with("///") && !text.starts_with("////")) || text.starts_with("/**")
+        }
+        _ => false,
+    }
+}
+
+fn symbol_kind(node: Node, scope: &Scope) -> Option<SymbolKind> {
+    let kind = match node.kind() {
+        "class_declaration" | "class_definition" => SymbolKind::Class,
+        "enum_item" => SymbolKind::Enum,
+        "impl_item" => SymbolKind::Impl,
+        "macro_definition" => SymbolKind::Macro,
+        "mod_item" => SymbolKind::Module,
+        "struct_item" => SymbolKind::Struct,
+        "trait_item" => SymbolKind::Trait,
+        "type_item" => SymbolKind::Type,
+        "method_definition" => SymbolKind::Method,
+        "arrow_function" if !is_named_arrow(node) => return None,
+        "arrow_function" | "function_declaration" | "function_definition" | "function_item"
+        | "function_signature_item" => {
+            if scope.kind.is_some_and(|kind| kind.is_container()) {
+                SymbolKind::Method
+            } else {
+                SymbolKind::Function
+            }
+        }
+        "variable_declaration" => container_type(node)?,
+        _ => return None,
+    };
+
+    Some(kind)
+}
+
+fn is_named_arrow(node: Node) -> bool {
+    node.parent()
+        .is_some_and(|parent| parent.kind() == "variable_declarator")
+}
+
+fn container_type(node: Node) -> Option<SymbolKind> {
+    let mut cursor = node.walk();
+
+    for child in node.named_children(&mut cursor) {
+        let kind = match child.kind() {
+            "struct_declaration" => SymbolKind::Struct,
+            "enum_declaration" => SymbolKind::Enum,
+            "union_declaration" => SymbolKind::Union,
+            "opaque_declaration" => SymbolKind::Type,
+            _ => continue,
+        };
+
+        return Some(kind);
+    }
+
+    None
+}
+
+fn symbol_name(node: Node, source: &[u8]) -> Option<String> {
+    match node.kind() {
+        "arrow_function" => node
+            .parent()
+            .and_then(|parent| parent.child_by_field_name("name"))
+            .map(|name| node_text(name, source)),
+        "impl_item" => {
+            let target = node_text(node.child_by_field_name("type")?, source);
+
+            match node.child_by_field_name("trait") {
+                Some(name) => Some(format!("{} for {}", node_text(name, source), target)),
+                None => Some(target),
+            }
+        }
+        "variable_declaration" => {
+            let mut cursor = node.walk();
+
+            node.named_children(&mut cursor)
+                .find(|child| child.kind() == "identifier")
+                .map(|name| node_text(name, source))
+        }
+        _ => node
+            .child_by_field_name("name")
+            .map(|name| node_text(name, source)),
+    }
+}
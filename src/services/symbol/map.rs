@@ -0,0 +1,42 @@
+use std::fmt::Write;
+
+use crate::model::error::SwarmResult;
+use crate::model::output::{OutputContext, OutputFile, OutputFormat};
+
+use super::index::{Symbol, SymbolIndex};
+
+pub struct SymbolMap;
+
+impl SymbolMap {
+    pub fn entries(index: &SymbolIndex) -> Vec<OutputFile> {
+        let mut entries: Vec<OutputFile> = Vec::new();
+
+        for symbol in index.symbols() {
+            match entries.last_mut() {
+                Some(entry) if entry.path == symbol.path => append_symbol(&mut entry.content, symbol),
+                _ => {
+                    let mut map = String::new();
+                    append_symbol(&mut map, symbol);
+                    entries.push(OutputFile::new(symbol.path.clone(), map));
+                }
+            }
+        }
+
+        entries
+    }
+
+    pub fn render(index: &SymbolIndex, format: OutputFormat, context: &OutputContext) -> SwarmResult<String> {
+        format.format_with(&Self::entries(index), context)
+    }
+}
+
+fn append_symbol(map: &mut String, symbol: &Symbol) {
+    let _ = writeln!(
+        map,
+        "{:>5}  {}{} {}",
+        symbol.line,
+        "    ".repeat(symbol.depth),
+        symbol.kind.label(),
+        symbol.name,
+    );
+}
@@ -0,0 +1,7 @@
+pub mod extract;
+pub mod index;
+pub mod map;
+
+pub use extract::{extract_symbols, SymbolQuery};
+pub use index::{Symbol, SymbolIndex, SymbolKind};
+pub use map::SymbolMap;
@@ -7,12 +7,23 @@ pub trait TreeFormat {
 }
 
 pub struct AsciiTreeFormat {
+    mark_checked: bool,
     use_icons: bool,
 }
 
 impl AsciiTreeFormat {
     pub fn new(use_icons: bool) -> Self {
-        Self { use_icons }
+        Self {
+            mark_checked: false,
+            use_icons,
+        }
+    }
+
+    pub fn with_marks(use_icons: bool) -> Self {
+        Self {
+            mark_checked: true,
+            use_icons,
+        }
     }
 
     fn icon(&self, node: &FileNode) -> &'static str {
@@ -30,8 +41,9 @@ impl AsciiTreeFormat {
         let connector = if is_last { "└── " } else { "├── " };
         let icon = self.icon(node);
         let name = node.file_name().unwrap_or_else(|| "Unknown".to_string());
+        let mark = if self.mark_checked && node.is_file() && node.checked { " (included)" } else { "" };
 
-        let _ = writeln!(output, "{}{}{}{}", prefix, connector, icon, name);
+        let _ = writeln!(output, "{}{}{}{}{}", prefix, connector, icon, name, mark);
 
         if node.is_directory() && !node.children.is_empty() {
             let child_prefix = format!("{}{}    ", prefix, if is_last { " " } else { "│" });
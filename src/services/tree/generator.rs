@@ -1,7 +1,13 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use crate::model::error::SwarmResult;
 use crate::model::node::FileNode;
 use crate::model::options::Options;
+use crate::model::output::TreeEmbed;
 
 use super::format::{AsciiTreeFormat, TreeFormat};
+use super::loader;
 
 pub struct TreeGenerator {
     formatter: Box<dyn TreeFormat>,
@@ -21,4 +27,56 @@ impl TreeGenerator {
     pub fn generate_tree(&self, nodes: &[FileNode]) -> String {
         self.formatter.format_tree(nodes)
     }
+
+    pub fn generate_embedded(
+        files: &[PathBuf],
+        project_root: Option<&Path>,
+        embed: TreeEmbed,
+        options: &Options,
+    ) -> SwarmResult<Option<String>> {
+        let Some(root) = common_ancestor(files) else {
+            return Ok(None);
+        };
+
+        match embed {
+            TreeEmbed::Off => Ok(None),
+            TreeEmbed::Selection => {
+                let nodes = loader::build_from_paths(&root, files);
+                Ok(Some(Self::new(options).generate_tree(&nodes)))
+            }
+            TreeEmbed::Project => {
+                let root = project_root
+                    .filter(|project_root| root.starts_with(project_root))
+                    .map_or(root, Path::to_path_buf);
+
+                let mut node = FileNode::new(root);
+                loader::load_all_children(&mut node, options)?;
+
+                let selected: HashSet<&PathBuf> = files.iter().collect();
+                mark_selected(&mut node.children, &selected);
+
+                let generator = Self::with_format(Box::new(AsciiTreeFormat::with_marks(options.use_icon)));
+                Ok(Some(generator.generate_tree(&node.children)))
+            }
+        }
+    }
+}
+
+fn mark_selected(nodes: &mut [FileNode], selected: &HashSet<&PathBuf>) {
+    for node in nodes {
+        node.checked = selected.contains(&node.path);
+        mark_selected(&mut node.children, selected);
+    }
+}
+
+fn common_ancestor(files: &[PathBuf]) -> Option<PathBuf> {
+    let mut root = files.first()?.parent()?.to_path_buf();
+
+    for file in &files[1..] {
+        while !file.starts_with(&root) {
+            root = root.parent()?.to_path_buf();
+        }
+    }
+
+    Some(root)
 }
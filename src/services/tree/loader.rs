@@ -1,4 +1,5 @@
 use std::fs;
+use std::path::{Path, PathBuf};
 
 use crate::model::error::SwarmResult;
 use crate::model::node::FileNode;
@@ -67,6 +68,44 @@ pub fn load_all_children(node: &mut FileNode, options: &Options) -> SwarmResult<
     Ok(has_visible_content)
 }
 
+pub fn build_from_paths(root: &Path, paths: &[PathBuf]) -> Vec<FileNode> {
+    let mut root_node = FileNode::new(root.to_path_buf());
+
+    for path in paths {
+        let Ok(relative) = path.strip_prefix(root) else {
+            continue;
+        };
+
+        let mut node = &mut root_node;
+        let mut current = root.to_path_buf();
+
+        for component in relative.components() {
+            current.push(component);
+
+            let position = match node.children.iter().position(|child| child.path == current) {
+                Some(position) => position,
+                None => {
+                    node.children.push(FileNode::new(current.clone()));
+                    node.children.len() - 1
+                }
+            };
+
+            node = &mut node.children[position];
+        }
+    }
+
+    sort_children(&mut root_node);
+    root_node.children
+}
+
+fn sort_children(node: &mut FileNode) {
+    node.children.sort_by_key(|child| (child.is_file(), child.lowercase_name()));
+
+    for child in &mut node.children {
+        sort_children(child);
+    }
+}
+
 pub fn refresh_node(node: &mut FileNode, options: &Options) -> SwarmResult<bool> {
     node.loaded = false;
     node.children.clear();
@@ -10,7 +10,7 @@ use crate::services::tree::traversal::Traversable;
 use super::core::{Worker, WorkerTask};
 
 pub enum BackgroundLoadCommand {
-    Start(Vec<FileNode>, Options),
+    Start(Vec<FileNode>, Box<Options>),
     Stop,
 }
 
@@ -142,7 +142,7 @@ impl BackgroundLoader {
             return false;
         }
 
-        self.worker.send(BackgroundLoadCommand::Start(nodes, options))
+        self.worker.send(BackgroundLoadCommand::Start(nodes, Box::new(options)))
     }
 
     pub fn stop(&self) {
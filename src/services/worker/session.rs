@@ -10,7 +10,7 @@ use super::core::{Worker, WorkerTask};
 
 pub enum SessionLoadCommand {
     Cancel,
-    Load(PathBuf, Options),
+    Load(PathBuf, Box<Options>),
 }
 
 pub enum SessionLoadResult {
@@ -104,7 +104,7 @@ impl SessionLoader {
             return false;
         }
 
-        let command = SessionLoadCommand::Load(path, options);
+        let command = SessionLoadCommand::Load(path, Box::new(options));
 
         if self.worker.send(command) {
             self.loading.store(true, Ordering::Relaxed);
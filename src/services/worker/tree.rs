@@ -14,7 +14,7 @@ pub enum TreeLoadStatus {
 }
 
 pub enum TreeLoadCommand {
-    Load(Vec<FileNode>, Options),
+    Load(Vec<FileNode>, Box<Options>),
     Stop,
 }
 
@@ -155,7 +155,7 @@ impl WorkerTask for TreeLoadTask {
     fn process(&mut self, command: Self::Command, result_sender: &Sender<Self::Result>) {
         match command {
             TreeLoadCommand::Load(nodes, options) => {
-                Self::process_tree_load(nodes, options, result_sender);
+                Self::process_tree_load(nodes, *options, result_sender);
             }
             TreeLoadCommand::Stop => {}
         }
@@ -203,7 +203,7 @@ impl TreeLoader {
             return false;
         }
 
-        if self.worker.send(TreeLoadCommand::Load(nodes, options)) {
+        if self.worker.send(TreeLoadCommand::Load(nodes, Box::new(options))) {
             self.status = TreeLoadStatus::InProgress;
             true
         } else {
@@ -2,7 +2,7 @@ use std::sync::mpsc::Sender;
 
 use eframe::egui;
 
-use crate::app::message::{Copy, Msg, Render, Skeleton};
+use crate::app::message::{Copy, Msg, Options_, Render, Skeleton, Symbols};
 use crate::app::state::{LoadStatus, Model, UiState};
 use crate::app::state::ui::GenerateMode;
 
@@ -39,8 +39,20 @@ pub fn render(
                         let _ = sender.send(Msg::Copy(Copy::Requested));
                     }
 
+                    if !model.options.prompts.is_empty() {
+                        render_prompt_selector(ui, model, sender);
+                    }
+
                     render_generate_split_button(ui, ui_state, sender, row_height, padding, tree_is_loading);
 
+                    if matches!(ui_state.generate_mode, GenerateMode::Skeleton | GenerateMode::Mixed) {
+                        let mut public_only = model.options.skeleton_public_only;
+
+                        if ui.checkbox(&mut public_only, "Public only").clicked() {
+                            let _ = sender.send(Msg::Options(Options_::SkeletonPublicOnlyChanged(public_only)));
+                        }
+                    }
+
                     ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                         ui.add_space(10.0);
 
@@ -69,6 +81,27 @@ pub fn render(
         });
 }
 
+fn render_prompt_selector(ui: &mut egui::Ui, model: &Model, sender: &Sender<Msg>) {
+    let selected = model.options.prompt.as_deref().unwrap_or("No prompt");
+
+    egui::ComboBox::from_id_salt("prompt_selector")
+        .selected_text(selected)
+        .width(120.0)
+        .show_ui(ui, |ui| {
+            if ui.selectable_label(model.options.prompt.is_none(), "No prompt").clicked() {
+                let _ = sender.send(Msg::Options(Options_::PromptSelected(None)));
+            }
+
+            for prompt in &model.options.prompts {
+                let is_selected = model.options.prompt.as_deref() == Some(prompt.name.as_str());
+
+                if ui.selectable_label(is_selected, &prompt.name).clicked() {
+                    let _ = sender.send(Msg::Options(Options_::PromptSelected(Some(prompt.name.clone()))));
+                }
+            }
+        });
+}
+
 fn render_generate_split_button(
     ui: &mut egui::Ui,
     ui_state: &UiState,
@@ -77,19 +110,31 @@ fn render_generate_split_button(
     padding: f32,
     tree_is_loading: bool,
 ) {
-    let any_gen_in_progress = ui_state.tree_gen_in_progress || ui_state.skeleton_gen_in_progress;
+    let mixed_in_progress = ui_state.generate_mode == GenerateMode::Mixed && ui_state.copy_in_progress;
+    let any_gen_in_progress = ui_state.tree_gen_in_progress
+        || ui_state.skeleton_gen_in_progress
+        || ui_state.symbols_gen_in_progress
+        || mixed_in_progress;
     let can_generate = !any_gen_in_progress && !tree_is_loading;
     let button_height = row_height + padding;
 
     let main_label = if any_gen_in_progress {
-        match ui_state.generate_mode {
-            GenerateMode::Tree => "Generating...",
-            GenerateMode::Skeleton => "Generating...",
+        let progress = match ui_state.generate_mode {
+            GenerateMode::Skeleton => ui_state.skeleton_progress,
+            GenerateMode::Symbols => ui_state.symbols_progress,
+            _ => None,
+        };
+
+        match progress {
+            Some((processed, total)) => format!("Generating... {}/{}", processed, total),
+            None => "Generating...".to_string(),
         }
     } else {
         match ui_state.generate_mode {
-            GenerateMode::Tree => "Generate Tree",
-            GenerateMode::Skeleton => "Generate Skeleton",
+            GenerateMode::Tree => "Generate Tree".to_string(),
+            GenerateMode::Skeleton => "Generate Skeleton".to_string(),
+            GenerateMode::Symbols => "Generate Symbols".to_string(),
+            GenerateMode::Mixed => "Generate Mixed".to_string(),
         }
     };
 
@@ -107,6 +152,12 @@ fn render_generate_split_button(
             GenerateMode::Skeleton => {
                 let _ = sender.send(Msg::Skeleton(Skeleton::Requested));
             }
+            GenerateMode::Symbols => {
+                let _ = sender.send(Msg::Symbols(Symbols::Requested));
+            }
+            GenerateMode::Mixed => {
+                let _ = sender.send(Msg::Copy(Copy::MixedRequested));
+            }
         }
     }
 
@@ -137,6 +188,20 @@ fn render_generate_split_button(
             ).clicked() {
                 let _ = sender.send(Msg::Skeleton(Skeleton::ModeChanged(GenerateMode::Skeleton)));
             }
+
+            if ui.selectable_label(
+                ui_state.generate_mode == GenerateMode::Symbols,
+                "Generate Symbols",
+            ).clicked() {
+                let _ = sender.send(Msg::Skeleton(Skeleton::ModeChanged(GenerateMode::Symbols)));
+            }
+
+            if ui.selectable_label(
+                ui_state.generate_mode == GenerateMode::Mixed,
+                "Generate Mixed",
+            ).clicked() {
+                let _ = sender.send(Msg::Skeleton(Skeleton::ModeChanged(GenerateMode::Mixed)));
+            }
         });
 }
 
@@ -192,13 +192,17 @@ fn render_node(
                     })).ok();
                 }
 
-                if ui.selectable_label(node.checked, &label).clicked() {
+                let response = ui.selectable_label(node.checked, &label);
+
+                if response.clicked() {
                     sender.send(Msg::Tree(Tree::NodeToggled {
                         path,
                         checked: !node.checked,
                         propagate: false,
                     })).ok();
                 }
+
+                render_node_context_menu(&response, node, sender);
             });
         }
         NodeKind::Directory => {
@@ -218,7 +222,7 @@ fn render_node(
                     .id_salt(&node.path)
                     .default_open(default_open);
 
-                header.show(ui, |ui| {
+                let collapsing = header.show(ui, |ui| {
                     if !node.loaded {
                         sender.send(Msg::Tree(Tree::NodeExpanded { path: path.clone() })).ok();
                         ui.spinner();
@@ -231,7 +235,18 @@ fn render_node(
                         }
                     }
                 });
+
+                render_node_context_menu(&collapsing.header_response, node, sender);
             });
         }
     }
 }
+
+fn render_node_context_menu(response: &egui::Response, node: &FileNode, sender: &Sender<Msg>) {
+    response.context_menu(|ui| {
+        if ui.button("Check Dependents").clicked() {
+            sender.send(Msg::Tree(Tree::DependentsRequested(node.path.clone()))).ok();
+            ui.close();
+        }
+    });
+}
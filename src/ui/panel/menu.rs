@@ -4,7 +4,7 @@ use std::thread;
 use eframe::egui;
 use rfd::FileDialog;
 
-use crate::app::message::{App, Msg, Options_, Session};
+use crate::app::message::{App, Msg, Options_, Session, Tree};
 use crate::app::state::{Model, UiState};
 use crate::constants::APP_NAME;
 
@@ -50,6 +50,15 @@ pub fn render(
                 });
 
                 ui.menu_button("Edit", |ui| {
+                    let has_selection = model.tree.nodes.iter().any(|node| node.is_selected());
+
+                    if ui.add_enabled(has_selection, egui::Button::new("Expand Dependencies")).clicked() {
+                        sender.send(Msg::Tree(Tree::DependenciesRequested)).ok();
+                        ui.close();
+                    }
+
+                    ui.separator();
+
                     if ui.button("Options").clicked() {
                         sender.send(Msg::Options(Options_::Opened)).ok();
                         ui.close();
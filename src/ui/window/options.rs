@@ -5,6 +5,8 @@ use eframe::egui;
 use crate::app::message::{Filter, Msg, Options_};
 use crate::app::state::{Model, UiState};
 use crate::app::state::OptionsTab;
+use crate::model::prompt::Prompt;
+use crate::model::template::{templates_dir, Template};
 use crate::ui::themes::Theme;
 
 pub fn render(
@@ -32,6 +34,7 @@ pub fn render(
                     OptionsTab::General => render_general(ui, model, sender),
                     OptionsTab::Includes => render_includes(ui, model, ui_state, sender),
                     OptionsTab::Excludes => render_excludes(ui, model, ui_state, sender),
+                    OptionsTab::Prompts => render_prompts(ui, model, ui_state, sender),
                 }
 
                 ui.add_space(ui.available_height() - 35.0);
@@ -58,6 +61,10 @@ fn render_tab_bar(ui: &mut egui::Ui, ui_state: &UiState, sender: &Sender<Msg>) {
         if ui.selectable_value(&mut current, OptionsTab::Excludes, "Exclude").clicked() {
             sender.send(Msg::Options(Options_::TabChanged(OptionsTab::Excludes))).ok();
         }
+
+        if ui.selectable_value(&mut current, OptionsTab::Prompts, "Prompts").clicked() {
+            sender.send(Msg::Options(Options_::TabChanged(OptionsTab::Prompts))).ok();
+        }
     });
 }
 
@@ -82,6 +89,16 @@ fn render_general(ui: &mut egui::Ui, model: &Model, sender: &Sender<Msg>) {
                         ui.separator();
                         ui.add_space(10.0);
 
+                        render_minify_section(ui, model, sender);
+                        ui.add_space(10.0);
+                        ui.separator();
+                        ui.add_space(10.0);
+
+                        render_truncate_section(ui, model, sender);
+                        ui.add_space(10.0);
+                        ui.separator();
+                        ui.add_space(10.0);
+
                         render_behavior_section(ui, model, sender);
                         ui.add_space(10.0);
                         ui.separator();
@@ -130,6 +147,233 @@ fn render_output_section(ui: &mut egui::Ui, model: &Model, sender: &Sender<Msg>)
                 }
             });
     });
+
+    if model.options.output_format == crate::model::output::OutputFormat::Template {
+        render_template_selector(ui, model, sender);
+    }
+
+    ui.add_space(5.0);
+
+    ui.horizontal(|ui| {
+        ui.label("Embed tree:");
+
+        egui::ComboBox::from_id_salt("embed_tree_selector")
+            .selected_text(model.options.embed_tree.name())
+            .width(150.0)
+            .show_ui(ui, |ui| {
+                for embed in crate::model::output::TreeEmbed::all() {
+                    if ui.selectable_label(model.options.embed_tree == *embed, embed.name()).clicked() {
+                        sender.send(Msg::Options(Options_::EmbedTreeChanged(*embed))).ok();
+                    }
+                }
+            });
+    });
+
+    ui.add_space(5.0);
+
+    let mut line_numbers = model.options.line_numbers;
+
+    if ui.checkbox(&mut line_numbers, "Prefix lines with line numbers").clicked() {
+        sender.send(Msg::Options(Options_::LineNumbersChanged(line_numbers))).ok();
+    }
+
+    ui.add_space(5.0);
+
+    ui.horizontal(|ui| {
+        ui.label("Skeleton:");
+
+        egui::ComboBox::from_id_salt("skeleton_level_selector")
+            .selected_text(model.options.skeleton_level.name())
+            .width(150.0)
+            .show_ui(ui, |ui| {
+                for level in crate::model::skeleton::SkeletonLevel::all() {
+                    if ui.selectable_label(model.options.skeleton_level == *level, level.name()).clicked() {
+                        sender.send(Msg::Options(Options_::SkeletonLevelChanged(*level))).ok();
+                    }
+                }
+            });
+    });
+
+    if model.options.skeleton_level == crate::model::skeleton::SkeletonLevel::Expanded {
+        ui.horizontal(|ui| {
+            ui.label("Expand bodies up to:");
+
+            let mut expand_lines = model.options.skeleton_expand_lines;
+
+            let response = ui.add(
+                egui::DragValue::new(&mut expand_lines)
+                    .range(1..=200)
+                    .suffix(" lines")
+            );
+
+            if response.changed() {
+                sender.send(Msg::Options(Options_::SkeletonExpandLinesChanged(expand_lines))).ok();
+            }
+        });
+    }
+
+    ui.add_space(5.0);
+
+    let mut doc_comments = model.options.skeleton_doc_comments;
+
+    if ui.checkbox(&mut doc_comments, "Keep doc comments in skeletons").clicked() {
+        sender.send(Msg::Options(Options_::SkeletonDocCommentsChanged(doc_comments))).ok();
+    }
+
+    ui.add_enabled_ui(doc_comments, |ui| {
+        let mut doc_summary = model.options.skeleton_doc_summary;
+
+        if ui.checkbox(&mut doc_summary, "Only keep the first paragraph").clicked() {
+            sender.send(Msg::Options(Options_::SkeletonDocSummaryChanged(doc_summary))).ok();
+        }
+    });
+}
+
+fn render_template_selector(ui: &mut egui::Ui, model: &Model, sender: &Sender<Msg>) {
+    let templates = Template::available();
+
+    ui.horizontal(|ui| {
+        ui.label("Template:");
+
+        let selected = model.options.output_template.as_deref().unwrap_or("None");
+
+        egui::ComboBox::from_id_salt("output_template_selector")
+            .selected_text(selected)
+            .width(150.0)
+            .show_ui(ui, |ui| {
+                for name in &templates {
+                    let is_selected = model.options.output_template.as_deref() == Some(name.as_str());
+
+                    if ui.selectable_label(is_selected, name).clicked() {
+                        sender.send(Msg::Options(Options_::OutputTemplateChanged(name.clone()))).ok();
+                    }
+                }
+            });
+    });
+
+    if templates.is_empty()
+        && let Some(dir) = templates_dir()
+    {
+        ui.label(
+            egui::RichText::new(format!("Add templates to {}", dir.display()))
+                .small()
+                .color(ui.visuals().weak_text_color())
+        );
+    }
+}
+
+fn render_minify_section(ui: &mut egui::Ui, model: &Model, sender: &Sender<Msg>) {
+    ui.label(egui::RichText::new("Minify").strong().color(ui.visuals().weak_text_color()));
+    ui.add_space(5.0);
+
+    let mut comments = model.options.minify_comments;
+
+    if ui.checkbox(&mut comments, "Strip comments").clicked() {
+        sender.send(Msg::Options(Options_::MinifyCommentsChanged(comments))).ok();
+    }
+
+    let mut blank_lines = model.options.minify_blank_lines;
+
+    if ui.checkbox(&mut blank_lines, "Collapse blank lines").clicked() {
+        sender.send(Msg::Options(Options_::MinifyBlankLinesChanged(blank_lines))).ok();
+    }
+
+    let mut trailing_whitespace = model.options.minify_trailing_whitespace;
+
+    if ui.checkbox(&mut trailing_whitespace, "Trim trailing whitespace").clicked() {
+        sender.send(Msg::Options(Options_::MinifyTrailingWhitespaceChanged(trailing_whitespace))).ok();
+    }
+
+    ui.add_space(5.0);
+
+    ui.horizontal(|ui| {
+        ui.label("Indentation:");
+
+        egui::ComboBox::from_id_salt("minify_indent_selector")
+            .selected_text(model.options.minify_indent.name())
+            .width(150.0)
+            .show_ui(ui, |ui| {
+                for style in crate::model::minify::IndentStyle::all() {
+                    if ui.selectable_label(model.options.minify_indent == *style, style.name()).clicked() {
+                        sender.send(Msg::Options(Options_::MinifyIndentChanged(*style))).ok();
+                    }
+                }
+            });
+    });
+}
+
+fn render_truncate_section(ui: &mut egui::Ui, model: &Model, sender: &Sender<Msg>) {
+    ui.label(egui::RichText::new("Truncation").strong().color(ui.visuals().weak_text_color()));
+    ui.add_space(5.0);
+
+    let unit = model.options.truncate_unit;
+    let mut enabled = model.options.truncate_limit.is_some();
+
+    if ui.checkbox(&mut enabled, "Truncate oversized files").clicked() {
+        let limit = enabled.then(|| unit.default_limit());
+        sender.send(Msg::Options(Options_::TruncateLimitChanged(limit))).ok();
+    }
+
+    let Some(mut limit) = model.options.truncate_limit else {
+        return;
+    };
+
+    ui.add_space(5.0);
+
+    ui.horizontal(|ui| {
+        ui.label("Limit:");
+
+        let response = ui.add(
+            egui::DragValue::new(&mut limit)
+                .range(1..=usize::MAX)
+        );
+
+        if response.changed() {
+            sender.send(Msg::Options(Options_::TruncateLimitChanged(Some(limit)))).ok();
+        }
+
+        egui::ComboBox::from_id_salt("truncate_unit_selector")
+            .selected_text(unit.name())
+            .width(80.0)
+            .show_ui(ui, |ui| {
+                for option in crate::model::truncate::TruncateUnit::all() {
+                    if ui.selectable_label(unit == *option, option.name()).clicked() {
+                        sender.send(Msg::Options(Options_::TruncateUnitChanged(*option))).ok();
+                    }
+                }
+            });
+    });
+
+    ui.horizontal(|ui| {
+        ui.label("Replace with:");
+
+        egui::ComboBox::from_id_salt("truncate_mode_selector")
+            .selected_text(model.options.truncate_mode.name())
+            .width(150.0)
+            .show_ui(ui, |ui| {
+                for mode in crate::model::truncate::TruncateMode::all() {
+                    if ui.selectable_label(model.options.truncate_mode == *mode, mode.name()).clicked() {
+                        sender.send(Msg::Options(Options_::TruncateModeChanged(*mode))).ok();
+                    }
+                }
+            });
+    });
+
+    ui.horizontal(|ui| {
+        ui.label("Keep:");
+
+        let mut excerpt = model.options.truncate_excerpt;
+
+        let response = ui.add(
+            egui::DragValue::new(&mut excerpt)
+                .range(1..=1000)
+                .suffix(" lines at each end")
+        );
+
+        if response.changed() {
+            sender.send(Msg::Options(Options_::TruncateExcerptChanged(excerpt))).ok();
+        }
+    });
 }
 
 fn render_behavior_section(ui: &mut egui::Ui, model: &Model, sender: &Sender<Msg>) {
@@ -147,6 +391,24 @@ fn render_behavior_section(ui: &mut egui::Ui, model: &Model, sender: &Sender<Msg
     if ui.checkbox(&mut single_instance, "Use a single instance (requires restart)").clicked() {
         sender.send(Msg::Options(Options_::SingleInstanceChanged(single_instance))).ok();
     }
+
+    ui.add_space(5.0);
+
+    ui.horizontal(|ui| {
+        ui.label("Expand dependencies up to:");
+
+        let mut dependency_depth = model.options.dependency_depth;
+
+        let response = ui.add(
+            egui::DragValue::new(&mut dependency_depth)
+                .range(1..=10)
+                .suffix(" levels")
+        );
+
+        if response.changed() {
+            sender.send(Msg::Options(Options_::DependencyDepthChanged(dependency_depth))).ok();
+        }
+    });
 }
 
 fn render_appearance_section(ui: &mut egui::Ui, model: &Model, sender: &Sender<Msg>) {
@@ -275,6 +537,108 @@ fn render_excludes(ui: &mut egui::Ui, model: &Model, ui_state: &UiState, sender:
         });
 }
 
+fn render_prompts(ui: &mut egui::Ui, model: &Model, ui_state: &UiState, sender: &Sender<Msg>) {
+    let prompts = &model.options.prompts;
+    let selected = ui_state.selected_prompt.filter(|&index| index < prompts.len()).or((!prompts.is_empty()).then_some(0));
+
+    egui::Frame::NONE
+        .inner_margin(egui::Margin::same(8))
+        .show(ui, |ui| {
+            ui.vertical(|ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Preset:");
+
+                    let selected_text = selected.map_or("None", |index| prompts[index].name.as_str());
+
+                    egui::ComboBox::from_id_salt("prompt_editor_selector")
+                        .selected_text(selected_text)
+                        .width(200.0)
+                        .show_ui(ui, |ui| {
+                            for (index, prompt) in prompts.iter().enumerate() {
+                                if ui.selectable_label(selected == Some(index), &prompt.name).clicked() {
+                                    sender.send(Msg::Options(Options_::PromptEditorSelected(index))).ok();
+                                }
+                            }
+                        });
+
+                    if ui.button("Add").clicked() {
+                        sender.send(Msg::Options(Options_::PromptAdded)).ok();
+                    }
+
+                    if let Some(index) = selected
+                        && ui.button("Remove").clicked()
+                    {
+                        sender.send(Msg::Options(Options_::PromptRemoved(index))).ok();
+                    }
+                });
+
+                ui.add_space(10.0);
+
+                match selected {
+                    Some(index) => render_prompt_editor(ui, index, &prompts[index], sender),
+                    None => {
+                        ui.label(
+                            egui::RichText::new("No prompt presets")
+                                .color(ui.visuals().weak_text_color())
+                        );
+                    }
+                }
+            });
+        });
+}
+
+fn render_prompt_editor(ui: &mut egui::Ui, index: usize, prompt: &Prompt, sender: &Sender<Msg>) {
+    let mut edited = prompt.clone();
+    let mut changed = false;
+
+    egui::ScrollArea::vertical()
+        .auto_shrink([false, false])
+        .max_height(190.0)
+        .show(ui, |ui| {
+            ui.horizontal(|ui| {
+                ui.label("Name:");
+
+                let name = ui.add(
+                    egui::TextEdit::singleline(&mut edited.name)
+                        .desired_width(ui.available_width())
+                );
+
+                changed |= name.changed() && !edited.name.trim().is_empty();
+            });
+
+            ui.add_space(5.0);
+            ui.label("Before output:");
+
+            changed |= ui.add(
+                egui::TextEdit::multiline(&mut edited.preamble)
+                    .hint_text("You are reviewing {{project}} on {{branch}}...")
+                    .desired_rows(4)
+                    .desired_width(f32::INFINITY)
+            ).changed();
+
+            ui.add_space(5.0);
+            ui.label("After output:");
+
+            changed |= ui.add(
+                egui::TextEdit::multiline(&mut edited.postscript)
+                    .desired_rows(3)
+                    .desired_width(f32::INFINITY)
+            ).changed();
+
+            ui.add_space(5.0);
+
+            ui.label(
+                egui::RichText::new("Variables: {{project}}, {{branch}}, {{file_count}}, {{token_count}}")
+                    .small()
+                    .color(ui.visuals().weak_text_color())
+            );
+        });
+
+    if changed {
+        sender.send(Msg::Options(Options_::PromptEdited(index, edited))).ok();
+    }
+}
+
 fn render_filter_input<F, G>(
     ui: &mut egui::Ui,
     current_value: &str,